@@ -1,34 +1,218 @@
 //! Scryfall provides daily exports of their card data in bulk files. Each of
-//! these files is represented as a bulk_data object via the API. URLs for files
-//! change their timestamp each day, and can be fetched programmatically.
+//! these files is represented as a bulk_data object via the API, a
+//! [`BulkData`]. URLs for files change their timestamp each day, and can be
+//! fetched programmatically.
 //!
 //! # Warning
 //!
 //! These bulk dumps are not paginated, this means that they will be potentially
 //! stored in memory in its entirety while being iterated over.
 //!
+//! To avoid this, the functions ending in `_iter`, like [`all_cards_iter`],
+//! return a [`BulkIter`] that deserializes the items one at a time while the
+//! file is being downloaded. A [`BulkIter`] can also read a bulk file that was
+//! saved to disk.
+//!
+//! To avoid downloading the same file again, a [`BulkCache`] keeps the latest
+//! version of each bulk file in a directory.
+//!
 //! See also: [Official Docs](https://scryfall.com/docs/api/bulk-data)
 
-use serde::de::DeserializeOwned;
-use serde::Deserialize;
+mod bulk_type;
+mod cache;
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::marker::PhantomData;
+use std::path::Path;
 
+use chrono::{DateTime, Utc};
+use serde::de::{self, DeserializeOwned};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+pub use self::bulk_type::BulkType;
+pub use self::cache::{BulkCache, BulkFileMetadata};
 use crate::card::Card;
+use crate::client::ScryfallClient;
+use crate::error::Error;
+use crate::list::ListIter;
+#[cfg(feature = "async")]
+use crate::list::ListStream;
 use crate::ruling::Ruling;
 use crate::uri::Uri;
-use crate::util::BULK_DATA_URL;
+use crate::util::{Uuid, BULK_DATA_PATH};
+
+/// A bulk data object, describing one of the bulk files Scryfall provides.
+///
+/// For documentation on its fields refer to the
+/// [bulk data object](https://scryfall.com/docs/api/bulk-data) on the official
+/// site.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[allow(missing_docs)]
+pub struct BulkData {
+    pub id: Uuid,
+    pub uri: Uri<BulkData>,
+    #[serde(rename = "type")]
+    pub bulk_type: BulkType,
+    pub name: String,
+    pub description: String,
+    pub download_uri: Url,
+    pub updated_at: DateTime<Utc>,
+    pub size: Option<u64>,
+    pub compressed_size: Option<u64>,
+    pub content_type: String,
+    pub content_encoding: String,
+}
+
+impl BulkData {
+    /// Returns the bulk data objects of all the bulk files Scryfall provides.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::bulk::{BulkData, BulkType};
+    /// assert!(BulkData::all()
+    ///     .unwrap()
+    ///     .any(|bulk| bulk.bulk_type == BulkType::OracleCards));
+    /// ```
+    pub fn all() -> crate::Result<ListIter<BulkData>> {
+        BulkData::all_with(ScryfallClient::global())
+    }
+
+    /// Same as [`all`](#method.all), but the request is made with the given
+    /// client.
+    pub fn all_with(client: &ScryfallClient) -> crate::Result<ListIter<BulkData>> {
+        Uri::from(client.endpoint(BULK_DATA_PATH)?).fetch_iter_with(client)
+    }
+
+    /// Returns the bulk data object with the given id.
+    pub fn by_id(id: Uuid) -> crate::Result<BulkData> {
+        BulkData::by_id_with(ScryfallClient::global(), id)
+    }
+
+    /// Same as [`by_id`](#method.by_id), but the request is made with the given
+    /// client.
+    pub fn by_id_with(client: &ScryfallClient, id: Uuid) -> crate::Result<BulkData> {
+        Uri::from(client.endpoint(BULK_DATA_PATH)?.join(&id.to_string())?).fetch_with(client)
+    }
+
+    /// Returns the bulk data object of the given type.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::bulk::{BulkData, BulkType};
+    /// let rulings = BulkData::of_type(BulkType::Rulings).unwrap();
+    /// assert_eq!(rulings.content_type, "application/json");
+    /// ```
+    pub fn of_type(bulk_type: BulkType) -> crate::Result<BulkData> {
+        BulkData::of_type_with(ScryfallClient::global(), bulk_type)
+    }
+
+    /// Same as [`of_type`](#method.of_type), but the request is made with the
+    /// given client.
+    pub fn of_type_with(client: &ScryfallClient, bulk_type: BulkType) -> crate::Result<BulkData> {
+        Uri::from(
+            client
+                .endpoint(BULK_DATA_PATH)?
+                .join(&bulk_type.to_string())?,
+        )
+        .fetch_with(client)
+    }
+
+    /// Downloads the bulk file and deserializes all of its items.
+    ///
+    /// The whole file is kept in memory. Prefer
+    /// [`download_iter`](#method.download_iter) for the larger files.
+    pub fn download<T: DeserializeOwned>(&self) -> crate::Result<Vec<T>> {
+        self.download_with(ScryfallClient::global())
+    }
+
+    /// Same as [`download`](#method.download), but the request is made with
+    /// the given client.
+    pub fn download_with<T: DeserializeOwned>(
+        &self,
+        client: &ScryfallClient,
+    ) -> crate::Result<Vec<T>> {
+        client.fetch(&self.download_uri)
+    }
 
-#[derive(Deserialize, Debug, Clone)]
-struct BulkObject<T> {
-    download_uri: Uri<Vec<T>>,
+    /// Downloads the bulk file, deserializing its items one at a time while
+    /// they're being downloaded. See [`BulkIter`].
+    pub fn download_iter<T: DeserializeOwned>(&self) -> crate::Result<BulkIter<T>> {
+        self.download_iter_with(ScryfallClient::global())
+    }
+
+    /// Same as [`download_iter`](#method.download_iter), but the request is
+    /// made with the given client.
+    pub fn download_iter_with<T: DeserializeOwned>(
+        &self,
+        client: &ScryfallClient,
+    ) -> crate::Result<BulkIter<T>> {
+        let response = client.get(&self.download_uri)?;
+        Ok(BulkIter::from_reader(response.into_reader()))
+    }
 }
 
-impl<T: DeserializeOwned> BulkObject<T> {
-    fn of_type(bulk_type: &str) -> crate::Result<Self> {
-        Uri::from(BULK_DATA_URL.join(bulk_type)?).fetch()
+#[cfg(feature = "async")]
+impl BulkData {
+    /// Asynchronous version of [`all`](#method.all).
+    pub async fn all_async() -> crate::Result<ListStream<BulkData>> {
+        BulkData::all_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`all_async`](#method.all_async), but the request is made with
+    /// the given client.
+    pub async fn all_async_with(client: &ScryfallClient) -> crate::Result<ListStream<BulkData>> {
+        Uri::from(client.endpoint(BULK_DATA_PATH)?)
+            .fetch_stream_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`by_id`](#method.by_id).
+    pub async fn by_id_async(id: Uuid) -> crate::Result<BulkData> {
+        BulkData::by_id_async_with(ScryfallClient::global(), id).await
+    }
+
+    /// Same as [`by_id_async`](#method.by_id_async), but the request is made
+    /// with the given client.
+    pub async fn by_id_async_with(client: &ScryfallClient, id: Uuid) -> crate::Result<BulkData> {
+        Uri::from(client.endpoint(BULK_DATA_PATH)?.join(&id.to_string())?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`of_type`](#method.of_type).
+    pub async fn of_type_async(bulk_type: BulkType) -> crate::Result<BulkData> {
+        BulkData::of_type_async_with(ScryfallClient::global(), bulk_type).await
+    }
+
+    /// Same as [`of_type_async`](#method.of_type_async), but the request is
+    /// made with the given client.
+    pub async fn of_type_async_with(
+        client: &ScryfallClient,
+        bulk_type: BulkType,
+    ) -> crate::Result<BulkData> {
+        Uri::from(
+            client
+                .endpoint(BULK_DATA_PATH)?
+                .join(&bulk_type.to_string())?,
+        )
+        .fetch_async_with(client)
+        .await
     }
 
-    fn download(&self) -> crate::Result<Vec<T>> {
-        self.download_uri.fetch()
+    /// Asynchronous version of [`download`](#method.download).
+    pub async fn download_async<T: DeserializeOwned>(&self) -> crate::Result<Vec<T>> {
+        self.download_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`download_async`](#method.download_async), but the request is
+    /// made with the given client.
+    pub async fn download_async_with<T: DeserializeOwned>(
+        &self,
+        client: &ScryfallClient,
+    ) -> crate::Result<Vec<T>> {
+        client.fetch_async(&self.download_uri).await
     }
 }
 
@@ -36,37 +220,482 @@ impl<T: DeserializeOwned> BulkObject<T> {
 /// Scryfall. The chosen sets for the cards are an attempt to return the most
 /// up-to-date recognizable version of the card.
 pub fn oracle_cards() -> crate::Result<Vec<Card>> {
-    BulkObject::of_type("oracle_cards")?.download()
+    oracle_cards_with(ScryfallClient::global())
+}
+
+/// Same as [`oracle_cards`], but the requests are made with the given client.
+pub fn oracle_cards_with(client: &ScryfallClient) -> crate::Result<Vec<Card>> {
+    BulkData::of_type_with(client, BulkType::OracleCards)?.download_with(client)
 }
 
 /// An iterator of Scryfall card objects that together contain all unique
 /// artworks. The chosen cards promote the best image scans.
 pub fn unique_artwork() -> crate::Result<Vec<Card>> {
-    BulkObject::of_type("unique_artwork")?.download()
+    unique_artwork_with(ScryfallClient::global())
+}
+
+/// Same as [`unique_artwork`], but the requests are made with the given client.
+pub fn unique_artwork_with(client: &ScryfallClient) -> crate::Result<Vec<Card>> {
+    BulkData::of_type_with(client, BulkType::UniqueArtwork)?.download_with(client)
 }
 
 /// An iterator containing every card object on Scryfall in English or the
 /// printed language if the card is only available in one language.
 pub fn default_cards() -> crate::Result<Vec<Card>> {
-    BulkObject::of_type("default_cards")?.download()
+    default_cards_with(ScryfallClient::global())
+}
+
+/// Same as [`default_cards`], but the requests are made with the given client.
+pub fn default_cards_with(client: &ScryfallClient) -> crate::Result<Vec<Card>> {
+    BulkData::of_type_with(client, BulkType::DefaultCards)?.download_with(client)
 }
 
 /// An iterator of every card object on Scryfall in every language.
 ///
 /// # Note
-/// This currently takes about 2GB of RAM before returning 👀.
+/// This currently takes about 2GB of RAM before returning 👀. Use
+/// [`all_cards_iter`] to process the cards without keeping them all in memory.
 pub fn all_cards() -> crate::Result<Vec<Card>> {
-    BulkObject::of_type("all_cards")?.download()
+    all_cards_with(ScryfallClient::global())
+}
+
+/// Same as [`all_cards`], but the requests are made with the given client.
+pub fn all_cards_with(client: &ScryfallClient) -> crate::Result<Vec<Card>> {
+    BulkData::of_type_with(client, BulkType::AllCards)?.download_with(client)
 }
 
 /// An iterator of all Rulings on Scryfall. Each ruling refers to cards via an
 /// `oracle_id`.
 pub fn rulings() -> crate::Result<Vec<Ruling>> {
-    BulkObject::of_type("rulings")?.download()
+    rulings_with(ScryfallClient::global())
+}
+
+/// Same as [`rulings`], but the requests are made with the given client.
+pub fn rulings_with(client: &ScryfallClient) -> crate::Result<Vec<Ruling>> {
+    BulkData::of_type_with(client, BulkType::Rulings)?.download_with(client)
+}
+
+/// Same as [`oracle_cards`], but the cards are deserialized one at a time
+/// while they're being downloaded.
+///
+/// # Examples
+/// ```rust,no_run
+/// let names = scryfall::bulk::oracle_cards_iter()
+///     .unwrap()
+///     .map(|card| card.map(|card| card.name))
+///     .collect::<scryfall::Result<Vec<_>>>()
+///     .unwrap();
+/// assert!(names.contains(&"Lightning Bolt".to_string()));
+/// ```
+pub fn oracle_cards_iter() -> crate::Result<BulkIter<Card>> {
+    oracle_cards_iter_with(ScryfallClient::global())
+}
+
+/// Same as [`oracle_cards_iter`], but the requests are made with the given
+/// client.
+pub fn oracle_cards_iter_with(client: &ScryfallClient) -> crate::Result<BulkIter<Card>> {
+    BulkData::of_type_with(client, BulkType::OracleCards)?.download_iter_with(client)
+}
+
+/// Same as [`unique_artwork`], but the cards are deserialized one at a time
+/// while they're being downloaded.
+pub fn unique_artwork_iter() -> crate::Result<BulkIter<Card>> {
+    unique_artwork_iter_with(ScryfallClient::global())
+}
+
+/// Same as [`unique_artwork_iter`], but the requests are made with the given
+/// client.
+pub fn unique_artwork_iter_with(client: &ScryfallClient) -> crate::Result<BulkIter<Card>> {
+    BulkData::of_type_with(client, BulkType::UniqueArtwork)?.download_iter_with(client)
+}
+
+/// Same as [`default_cards`], but the cards are deserialized one at a time
+/// while they're being downloaded.
+pub fn default_cards_iter() -> crate::Result<BulkIter<Card>> {
+    default_cards_iter_with(ScryfallClient::global())
+}
+
+/// Same as [`default_cards_iter`], but the requests are made with the given
+/// client.
+pub fn default_cards_iter_with(client: &ScryfallClient) -> crate::Result<BulkIter<Card>> {
+    BulkData::of_type_with(client, BulkType::DefaultCards)?.download_iter_with(client)
+}
+
+/// Same as [`all_cards`], but the cards are deserialized one at a time while
+/// they're being downloaded, so that only a few of them are in memory at any
+/// given time.
+pub fn all_cards_iter() -> crate::Result<BulkIter<Card>> {
+    all_cards_iter_with(ScryfallClient::global())
+}
+
+/// Same as [`all_cards_iter`], but the requests are made with the given client.
+pub fn all_cards_iter_with(client: &ScryfallClient) -> crate::Result<BulkIter<Card>> {
+    BulkData::of_type_with(client, BulkType::AllCards)?.download_iter_with(client)
+}
+
+/// Same as [`rulings`], but the rulings are deserialized one at a time while
+/// they're being downloaded.
+pub fn rulings_iter() -> crate::Result<BulkIter<Ruling>> {
+    rulings_iter_with(ScryfallClient::global())
+}
+
+/// Same as [`rulings_iter`], but the requests are made with the given client.
+pub fn rulings_iter_with(client: &ScryfallClient) -> crate::Result<BulkIter<Ruling>> {
+    BulkData::of_type_with(client, BulkType::Rulings)?.download_iter_with(client)
+}
+
+/// An iterator that deserializes the items of a bulk file one at a time, as
+/// they're read.
+///
+/// Bulk files are JSON arrays, which can be hundreds of megabytes long. Instead
+/// of parsing the whole array at once, this iterator reads the array from its
+/// source one element at a time, so only the element being deserialized is
+/// kept in memory.
+///
+/// If the data is malformed or can't be read, the error is yielded and the
+/// iteration stops.
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::bulk::BulkIter;
+/// use scryfall::Card;
+///
+/// let mut count = 0;
+/// for card in BulkIter::<Card>::open("all-cards.json").unwrap() {
+///     if card.unwrap().reserved {
+///         count += 1;
+///     }
+/// }
+/// println!("{} cards are on the reserved list", count);
+/// ```
+pub struct BulkIter<T> {
+    reader: BufReader<Box<dyn Read + Send>>,
+    state: State,
+    _marker: PhantomData<fn() -> T>,
+}
+
+/// How much of the array a [`BulkIter`] has read.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+enum State {
+    /// The opening bracket hasn't been read yet.
+    Start,
+    /// At least one element has been read.
+    Elements,
+    /// The closing bracket has been read, or an error occurred.
+    Done,
+}
+
+impl<T> BulkIter<T> {
+    /// Creates an iterator over the bulk data read from `reader`.
+    pub fn from_reader(reader: impl Read + Send + 'static) -> Self {
+        BulkIter {
+            reader: BufReader::new(Box::new(reader)),
+            state: State::Start,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates an iterator over the bulk data stored in the file at `path`.
+    pub fn open(path: impl AsRef<Path>) -> crate::Result<Self> {
+        Ok(BulkIter::from_reader(File::open(path)?))
+    }
+
+    /// Skips any whitespace, and consumes and returns the next byte.
+    fn next_byte(&mut self) -> io::Result<Option<u8>> {
+        let next = self.peek_byte()?;
+        if next.is_some() {
+            self.reader.consume(1);
+        }
+        Ok(next)
+    }
+
+    /// Skips any whitespace, and returns the next byte without consuming it.
+    fn peek_byte(&mut self) -> io::Result<Option<u8>> {
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                return Ok(None);
+            }
+            match buf.iter().position(|b| !b.is_ascii_whitespace()) {
+                Some(i) => {
+                    let byte = buf[i];
+                    self.reader.consume(i);
+                    return Ok(Some(byte));
+                },
+                None => {
+                    let len = buf.len();
+                    self.reader.consume(len);
+                },
+            }
+        }
+    }
+}
+
+impl<T: DeserializeOwned> BulkIter<T> {
+    fn next_element(&mut self) -> crate::Result<Option<T>> {
+        match self.state {
+            State::Done => return Ok(None),
+            State::Start => {
+                match self.next_byte()? {
+                    Some(b'[') => {},
+                    other => return Err(unexpected(other, "`[`")),
+                }
+                if self.peek_byte()? == Some(b']') {
+                    self.reader.consume(1);
+                    self.state = State::Done;
+                    return Ok(None);
+                }
+            },
+            State::Elements => match self.next_byte()? {
+                Some(b',') => {},
+                Some(b']') => {
+                    self.state = State::Done;
+                    return Ok(None);
+                },
+                other => return Err(unexpected(other, "`,` or `]`")),
+            },
+        }
+        self.state = State::Elements;
+
+        // Bulk files contain arrays of objects, so the deserializer stops right
+        // after the closing brace of the element, without reading any further.
+        let mut deserializer = serde_json::Deserializer::from_reader(&mut self.reader);
+        Ok(Some(T::deserialize(&mut deserializer)?))
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for BulkIter<T> {
+    type Item = crate::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_element() {
+            Ok(element) => element.map(Ok),
+            Err(e) => {
+                self.state = State::Done;
+                Some(Err(e))
+            },
+        }
+    }
+}
+
+/// The error for an unexpected byte, or end of data, in a bulk file.
+fn unexpected(found: Option<u8>, expected: &str) -> Error {
+    let found = match found {
+        Some(byte) => format!("`{}`", byte as char),
+        None => "the end of the data".to_string(),
+    };
+    Error::JsonError(de::Error::custom(format!(
+        "expected {} in bulk data, found {}",
+        expected, found
+    )))
+}
+
+/// Asynchronous version of [`oracle_cards`].
+#[cfg(feature = "async")]
+pub async fn oracle_cards_async() -> crate::Result<Vec<Card>> {
+    oracle_cards_async_with(ScryfallClient::global()).await
+}
+
+/// Same as [`oracle_cards_async`], but the requests are made with the given
+/// client.
+#[cfg(feature = "async")]
+pub async fn oracle_cards_async_with(client: &ScryfallClient) -> crate::Result<Vec<Card>> {
+    BulkData::of_type_async_with(client, BulkType::OracleCards)
+        .await?
+        .download_async_with(client)
+        .await
+}
+
+/// Asynchronous version of [`unique_artwork`].
+#[cfg(feature = "async")]
+pub async fn unique_artwork_async() -> crate::Result<Vec<Card>> {
+    unique_artwork_async_with(ScryfallClient::global()).await
+}
+
+/// Same as [`unique_artwork_async`], but the requests are made with the given
+/// client.
+#[cfg(feature = "async")]
+pub async fn unique_artwork_async_with(client: &ScryfallClient) -> crate::Result<Vec<Card>> {
+    BulkData::of_type_async_with(client, BulkType::UniqueArtwork)
+        .await?
+        .download_async_with(client)
+        .await
+}
+
+/// Asynchronous version of [`default_cards`].
+#[cfg(feature = "async")]
+pub async fn default_cards_async() -> crate::Result<Vec<Card>> {
+    default_cards_async_with(ScryfallClient::global()).await
+}
+
+/// Same as [`default_cards_async`], but the requests are made with the given
+/// client.
+#[cfg(feature = "async")]
+pub async fn default_cards_async_with(client: &ScryfallClient) -> crate::Result<Vec<Card>> {
+    BulkData::of_type_async_with(client, BulkType::DefaultCards)
+        .await?
+        .download_async_with(client)
+        .await
+}
+
+/// Asynchronous version of [`all_cards`].
+#[cfg(feature = "async")]
+pub async fn all_cards_async() -> crate::Result<Vec<Card>> {
+    all_cards_async_with(ScryfallClient::global()).await
+}
+
+/// Same as [`all_cards_async`], but the request is made with the given client.
+#[cfg(feature = "async")]
+pub async fn all_cards_async_with(client: &ScryfallClient) -> crate::Result<Vec<Card>> {
+    BulkData::of_type_async_with(client, BulkType::AllCards)
+        .await?
+        .download_async_with(client)
+        .await
+}
+
+/// Asynchronous version of [`rulings`].
+#[cfg(feature = "async")]
+pub async fn rulings_async() -> crate::Result<Vec<Ruling>> {
+    rulings_async_with(ScryfallClient::global()).await
+}
+
+/// Same as [`rulings_async`], but the request is made with the given client.
+#[cfg(feature = "async")]
+pub async fn rulings_async_with(client: &ScryfallClient) -> crate::Result<Vec<Ruling>> {
+    BulkData::of_type_async_with(client, BulkType::Rulings)
+        .await?
+        .download_async_with(client)
+        .await
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io::Cursor;
+
+    use url::Url;
+
+    use super::{BulkData, BulkIter, BulkType};
+    use crate::client::test_server::{TestResponse, TestServer};
+    use crate::error::Error;
+    use crate::ruling::Ruling;
+
+    const RULINGS: &str = r#"[
+        {
+            "object": "ruling",
+            "oracle_id": "0004ebd0-dfd6-4276-b4a6-de0003e94237",
+            "source": "wotc",
+            "published_at": "2004-10-04",
+            "comment": "If there are two of these on the battlefield, they do not add together."
+        },
+        {
+            "object": "ruling",
+            "oracle_id": "0007c283-5b7a-4c00-9ca1-b455c8dff8c3",
+            "source": "wotc",
+            "published_at": "2019-08-23",
+            "comment": "The \"commander tax\" increases based on how many times a commander was cast."
+        }
+    ]"#;
+
+    fn bulk_data(url: &Url) -> String {
+        format!(
+            r#"{{"object":"bulk_data","id":"2ea4afda-4a8f-4ff1-b4f2-6e4ad2b4b8c2","type":"rulings","uri":"{0}bulk-data/2ea4afda-4a8f-4ff1-b4f2-6e4ad2b4b8c2","name":"Rulings","description":"A JSON file containing all Rulings on Scryfall.","download_uri":"{0}rulings.json","updated_at":"2021-01-01T10:00:00.000+00:00","size":1000,"content_type":"application/json","content_encoding":"gzip"}}"#,
+            url
+        )
+    }
+
+    #[test]
+    fn bulk_data_all() {
+        let server = TestServer::from_fn(|url| {
+            vec![TestResponse::json(&format!(
+                r#"{{"object":"list","has_more":false,"data":[{}]}}"#,
+                bulk_data(url)
+            ))]
+        });
+        let all = BulkData::all_with(&server.client())
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].bulk_type, BulkType::Rulings);
+        assert_eq!(all[0].size, Some(1000));
+        assert_eq!(all[0].compressed_size, None);
+        assert_eq!(all[0].content_encoding, "gzip");
+        assert_eq!(server.requests()[0].path, "/bulk-data/");
+    }
+
+    #[test]
+    fn bulk_type_names() {
+        for bulk_type in &[
+            BulkType::OracleCards,
+            BulkType::UniqueArtwork,
+            BulkType::DefaultCards,
+            BulkType::AllCards,
+            BulkType::Rulings,
+            BulkType::Other("all_tokens".to_string()),
+        ] {
+            let json = serde_json::to_string(bulk_type).unwrap();
+            assert_eq!(json, format!("\"{}\"", bulk_type));
+            assert_eq!(&serde_json::from_str::<BulkType>(&json).unwrap(), bulk_type);
+        }
+    }
+
+    #[test]
+    fn bulk_iter_reads_each_element() {
+        let rulings = BulkIter::<Ruling>::from_reader(RULINGS.as_bytes())
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(rulings.len(), 2);
+        assert!(rulings[1].comment.starts_with("The \"commander tax\""));
+    }
+
+    #[test]
+    fn bulk_iter_empty_array() {
+        assert!(BulkIter::<Ruling>::from_reader(" [ ]\n".as_bytes())
+            .next()
+            .is_none());
+        assert!(matches!(
+            BulkIter::<Ruling>::from_reader("".as_bytes()).next(),
+            Some(Err(Error::JsonError(_)))
+        ));
+    }
+
+    #[test]
+    fn bulk_iter_stops_at_malformed_data() {
+        let truncated = &RULINGS[..RULINGS.rfind('}').unwrap()];
+        let mut iter = BulkIter::<Ruling>::from_reader(truncated.as_bytes());
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+
+        let mut iter = BulkIter::<Ruling>::from_reader(Cursor::new(RULINGS.replace("},", "}")));
+        assert!(iter.next().unwrap().is_ok());
+        match iter.next().unwrap().unwrap_err() {
+            Error::JsonError(e) => assert!(e.to_string().contains("expected `,` or `]`")),
+            other => panic!("Wrong error type: {0} {0:?}", other),
+        }
+    }
+
+    #[test]
+    fn rulings_iter_streams_the_download() {
+        let server = TestServer::from_fn(|url| {
+            vec![
+                TestResponse::json(&bulk_data(url)),
+                TestResponse::json(RULINGS),
+            ]
+        });
+        let rulings = super::rulings_iter_with(&server.client())
+            .unwrap()
+            .collect::<crate::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(rulings.len(), 2);
+
+        let paths = server
+            .requests()
+            .into_iter()
+            .map(|r| r.path)
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["/bulk-data/rulings", "/rulings.json"]);
+    }
+
     #[test]
     #[ignore]
     fn oracle_cards() {
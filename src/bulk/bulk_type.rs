@@ -0,0 +1,46 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The kinds of bulk files Scryfall provides.
+///
+/// For documentation on each kind refer to the
+/// [bulk data docs](https://scryfall.com/docs/api/bulk-data) on the official
+/// site.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BulkType {
+    /// One card object for each Oracle ID.
+    OracleCards,
+    /// Cards that together contain all unique artworks.
+    UniqueArtwork,
+    /// Every card object in English, or in the printed language if the card is
+    /// only available in one language.
+    DefaultCards,
+    /// Every card object in every language.
+    AllCards,
+    /// All the rulings.
+    Rulings,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl fmt::Display for BulkType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use BulkType::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                OracleCards => "oracle_cards",
+                UniqueArtwork => "unique_artwork",
+                DefaultCards => "default_cards",
+                AllCards => "all_cards",
+                Rulings => "rulings",
+                Other(name) => name,
+            }
+        )
+    }
+}
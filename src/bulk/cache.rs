@@ -0,0 +1,307 @@
+//! A directory that keeps the latest version of each bulk file, so that it's
+//! only downloaded again when Scryfall publishes a new one.
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use super::{BulkData, BulkIter, BulkType};
+use crate::client::ScryfallClient;
+use crate::error::Error;
+
+/// A cache of bulk files stored in a directory.
+///
+/// Before reading a bulk file, the cache requests its bulk data object from
+/// the API, and compares its `updated_at` timestamp with the one recorded when
+/// the local copy was downloaded. The file is only downloaded again if
+/// Scryfall has published a newer version.
+///
+/// Each bulk file is stored as `<type>.json`, next to a `<type>.meta.json`
+/// file holding its [`BulkFileMetadata`].
+///
+/// A cache created with [`BulkCache::offline`] never contacts the API, and
+/// reads whichever copy was downloaded last.
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::bulk::{BulkCache, BulkType};
+/// use scryfall::Card;
+///
+/// let cache = BulkCache::new("/tmp/scryfall");
+/// // Only downloads the file the first time, or if it changed since.
+/// for card in cache.iter::<Card>(BulkType::OracleCards).unwrap() {
+///     println!("{}", card.unwrap().name);
+/// }
+///
+/// // Later, without network access.
+/// let offline = BulkCache::offline("/tmp/scryfall");
+/// let cards = offline.load::<Card>(BulkType::OracleCards).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct BulkCache {
+    dir: PathBuf,
+    client: Option<ScryfallClient>,
+}
+
+/// Information about a bulk file stored in a [`BulkCache`], taken from its bulk
+/// data object when it was downloaded.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct BulkFileMetadata {
+    /// When Scryfall last updated the file.
+    pub updated_at: DateTime<Utc>,
+    /// The size of the file in bytes, as reported by Scryfall.
+    pub size: Option<u64>,
+    /// The MIME type of the file.
+    pub content_type: String,
+}
+
+impl BulkCache {
+    /// Creates a cache that stores its files in `dir`, and checks for new
+    /// versions using the default client. The directory is created when the
+    /// first file is downloaded.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        BulkCache::new_with(ScryfallClient::global(), dir)
+    }
+
+    /// Same as [`new`](#method.new), but the requests are made with the given
+    /// client.
+    pub fn new_with(client: &ScryfallClient, dir: impl Into<PathBuf>) -> Self {
+        BulkCache {
+            dir: dir.into(),
+            client: Some(client.clone()),
+        }
+    }
+
+    /// Creates a cache that reads the files stored in `dir`, without ever
+    /// contacting the API.
+    pub fn offline(dir: impl Into<PathBuf>) -> Self {
+        BulkCache {
+            dir: dir.into(),
+            client: None,
+        }
+    }
+
+    /// The directory the files are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Checks whether this cache was created with
+    /// [`offline`](#method.offline).
+    pub fn is_offline(&self) -> bool {
+        self.client.is_none()
+    }
+
+    /// The path where the bulk file of type `bulk_type` is stored. The file
+    /// may not exist yet.
+    ///
+    /// Characters of the type's name other than lowercase letters, digits,
+    /// `-` and `_` are replaced with `_`, so that the file is always directly
+    /// in the cache's directory.
+    pub fn path(&self, bulk_type: BulkType) -> PathBuf {
+        self.dir.join(format!("{}.json", file_stem(&bulk_type)))
+    }
+
+    /// Returns the metadata of the stored bulk file of type `bulk_type`, or
+    /// `None` if it hasn't been downloaded.
+    pub fn metadata(&self, bulk_type: BulkType) -> crate::Result<Option<BulkFileMetadata>> {
+        if !self.path(bulk_type.clone()).is_file() {
+            return Ok(None);
+        }
+        match File::open(self.metadata_path(bulk_type)) {
+            Ok(file) => Ok(Some(serde_json::from_reader(BufReader::new(file))?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Makes sure the stored bulk file of type `bulk_type` is the latest one,
+    /// downloading it if it isn't, and returns its metadata.
+    ///
+    /// An offline cache returns the metadata of the stored file, or an error if
+    /// there's none.
+    pub fn update(&self, bulk_type: BulkType) -> crate::Result<BulkFileMetadata> {
+        let cached = self.metadata(bulk_type.clone())?;
+        let client = match &self.client {
+            Some(client) => client,
+            None => {
+                return cached.ok_or_else(|| {
+                    Error::Other(format!(
+                        "No cached {} bulk file in {}",
+                        bulk_type,
+                        self.dir.display()
+                    ))
+                })
+            },
+        };
+
+        let bulk_data = BulkData::of_type_with(client, bulk_type.clone())?;
+        let metadata = BulkFileMetadata {
+            updated_at: bulk_data.updated_at,
+            size: bulk_data.size,
+            content_type: bulk_data.content_type.clone(),
+        };
+        if cached.as_ref() == Some(&metadata) {
+            return Ok(metadata);
+        }
+
+        self.download(client, &bulk_data)?;
+        let mut writer = BufWriter::new(File::create(self.metadata_path(bulk_type))?);
+        serde_json::to_writer(&mut writer, &metadata)?;
+        writer.flush()?;
+        Ok(metadata)
+    }
+
+    /// Updates the bulk file of type `bulk_type`, then creates an iterator that
+    /// reads its items from disk one at a time. See [`BulkIter`].
+    pub fn iter<T: DeserializeOwned>(&self, bulk_type: BulkType) -> crate::Result<BulkIter<T>> {
+        self.update(bulk_type.clone())?;
+        BulkIter::open(self.path(bulk_type))
+    }
+
+    /// Updates the bulk file of type `bulk_type`, then reads all of its items
+    /// from disk.
+    pub fn load<T: DeserializeOwned>(&self, bulk_type: BulkType) -> crate::Result<Vec<T>> {
+        self.update(bulk_type.clone())?;
+        let file = File::open(self.path(bulk_type))?;
+        Ok(serde_json::from_reader(BufReader::new(file))?)
+    }
+
+    fn metadata_path(&self, bulk_type: BulkType) -> PathBuf {
+        self.dir
+            .join(format!("{}.meta.json", file_stem(&bulk_type)))
+    }
+
+    /// Downloads a bulk file to a temporary file, which replaces the stored
+    /// one once complete so that an interrupted download leaves it intact.
+    fn download(&self, client: &ScryfallClient, bulk_data: &BulkData) -> crate::Result<()> {
+        let bulk_type = &bulk_data.bulk_type;
+        fs::create_dir_all(&self.dir)?;
+        let partial = self.dir.join(format!("{}.json.part", file_stem(bulk_type)));
+        let mut reader = client.get(&bulk_data.download_uri)?.into_reader();
+        let mut writer = BufWriter::new(File::create(&partial)?);
+        io::copy(&mut reader, &mut writer)?;
+        writer.flush()?;
+        fs::rename(&partial, self.path(bulk_type.clone()))?;
+        Ok(())
+    }
+}
+
+/// The name of the files of `bulk_type`, which can be anything when it's a
+/// type this crate doesn't know about.
+fn file_stem(bulk_type: &BulkType) -> String {
+    let stem = bulk_type
+        .to_string()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect::<String>();
+    if stem.is_empty() {
+        "_".to_string()
+    } else {
+        stem
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::{TestResponse, TestServer};
+    use crate::ruling::Ruling;
+
+    const RULINGS: &str = r#"[{"object":"ruling","oracle_id":"0004ebd0-dfd6-4276-b4a6-de0003e94237","source":"wotc","published_at":"2004-10-04","comment":"They do not add together."}]"#;
+
+    fn bulk_object(url: &url::Url, updated_at: &str) -> TestResponse {
+        TestResponse::json(&format!(
+            r#"{{"object":"bulk_data","id":"2ea4afda-4a8f-4ff1-b4f2-6e4ad2b4b8c2","type":"rulings","uri":"{0}bulk-data/rulings","name":"Rulings","description":"All the rulings.","download_uri":"{0}rulings.json","updated_at":"{1}","size":{2},"content_type":"application/json","content_encoding":"gzip"}}"#,
+            url,
+            updated_at,
+            RULINGS.len()
+        ))
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("scryfall-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_only_new_versions() {
+        let server = TestServer::from_fn(|url| {
+            vec![
+                bulk_object(url, "2021-01-01T10:00:00.000+00:00"),
+                TestResponse::json(RULINGS),
+                bulk_object(url, "2021-01-01T10:00:00.000+00:00"),
+                bulk_object(url, "2021-01-02T10:00:00.000+00:00"),
+                TestResponse::json(RULINGS),
+            ]
+        });
+        let dir = temp_dir("bulk-cache");
+        let cache = BulkCache::new_with(&server.client(), &dir);
+        assert_eq!(cache.metadata(BulkType::Rulings).unwrap(), None);
+
+        let rulings = cache.load::<Ruling>(BulkType::Rulings).unwrap();
+        assert_eq!(rulings.len(), 1);
+        let metadata = cache.metadata(BulkType::Rulings).unwrap().unwrap();
+        assert_eq!(metadata.size, Some(RULINGS.len() as u64));
+        assert_eq!(metadata.content_type, "application/json");
+
+        let rulings = cache.iter::<Ruling>(BulkType::Rulings).unwrap();
+        assert_eq!(rulings.count(), 1);
+        assert_eq!(server.requests().len(), 3);
+
+        let updated = cache.update(BulkType::Rulings).unwrap();
+        assert!(updated.updated_at > metadata.updated_at);
+        let paths = server
+            .requests()
+            .into_iter()
+            .map(|r| r.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "/bulk-data/rulings",
+                "/rulings.json",
+                "/bulk-data/rulings",
+                "/bulk-data/rulings",
+                "/rulings.json",
+            ]
+        );
+
+        let offline = BulkCache::offline(&dir);
+        assert_eq!(offline.update(BulkType::Rulings).unwrap(), updated);
+        assert_eq!(offline.load::<Ruling>(BulkType::Rulings).unwrap().len(), 1);
+        assert!(matches!(
+            offline.update(BulkType::AllCards),
+            Err(Error::Other(_))
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn unknown_types_stay_in_the_directory() {
+        let dir = temp_dir("bulk-cache-names");
+        let cache = BulkCache::offline(&dir);
+        let hostile = BulkType::Other("../../etc/Passwd.d".to_string());
+        assert_eq!(
+            cache.path(hostile.clone()),
+            dir.join("______etc__asswd_d.json")
+        );
+        assert_eq!(
+            cache.metadata_path(hostile),
+            dir.join("______etc__asswd_d.meta.json")
+        );
+        assert_eq!(
+            cache.path(BulkType::Other(String::new())),
+            dir.join("_.json")
+        );
+        assert_eq!(cache.path(BulkType::AllCards), dir.join("all_cards.json"));
+    }
+}
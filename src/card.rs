@@ -5,40 +5,64 @@
 //! documented in the official [scryfall page](https://scryfall.com/docs/api/cards).
 mod border_color;
 mod card_faces;
+mod collection;
 mod color;
+mod finish;
 mod frame;
 mod frame_effect;
 mod game;
+mod image_cache;
+mod image_uris;
+mod language;
 mod layout;
+mod legalities;
 mod legality;
 mod preview;
 mod price;
 mod rarity;
 mod related_card;
+mod security_stamp;
+mod type_line;
 
 use std::collections::hash_map::HashMap;
+use std::io::{self, Write};
 
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use url::Url;
 
 pub use self::border_color::BorderColor;
 pub use self::card_faces::CardFace;
+pub use self::collection::{CardIdentifier, Collection};
+use self::collection::{CollectionRequest, MAX_IDENTIFIERS};
 pub use self::color::{Color, Colors};
+pub use self::finish::Finish;
 pub use self::frame::Frame;
 pub use self::frame_effect::FrameEffect;
 pub use self::game::Game;
+pub use self::image_cache::ImageCache;
+pub use self::image_uris::{ImageFace, ImageSize, ImageStatus, ImageUris};
+pub use self::language::Language;
 pub use self::layout::Layout;
+pub use self::legalities::Legalities;
 pub use self::legality::Legality;
 pub use self::preview::Preview;
-pub use self::price::Price;
+pub use self::price::{Currency, Decimal, Price};
 pub use self::rarity::Rarity;
-pub use self::related_card::RelatedCard;
+pub use self::related_card::{Component, RelatedCard};
+pub use self::security_stamp::SecurityStamp;
+pub use self::type_line::{SubtypeCatalogs, TypeLine, TypeLineFace};
 use crate::card_searcher::Search;
+use crate::catalog::Catalog;
+use crate::client::ScryfallClient;
+#[cfg(feature = "async")]
+use crate::list::ListStream;
 use crate::list::{List, ListIter};
 use crate::ruling::Ruling;
-use crate::set::Set;
+use crate::set::{Set, SetType};
+use crate::symbology::ManaCost;
 use crate::uri::Uri;
-use crate::util::{Uuid, CARDS_URL};
+use crate::util::{Uuid, CARDS_PATH};
 
 /// A Card object containing all fields that `scryfall` provides,
 ///
@@ -50,12 +74,14 @@ pub struct Card {
     // Core card fields
     pub arena_id: Option<usize>,
     pub id: Uuid,
-    pub lang: String,
+    pub lang: Language,
     pub mtgo_id: Option<usize>,
     pub mtgo_foil_id: Option<usize>,
     pub multiverse_ids: Option<Vec<usize>>,
     pub tcgplayer_id: Option<usize>,
-    pub oracle_id: Uuid,
+    pub tcgplayer_etched_id: Option<usize>,
+    pub cardmarket_id: Option<usize>,
+    pub oracle_id: Option<Uuid>,
     pub prints_search_uri: Uri<List<Card>>,
     pub rulings_uri: Uri<Vec<Ruling>>,
     pub scryfall_uri: String,
@@ -63,35 +89,53 @@ pub struct Card {
     // Gameplay Fields
     pub card_faces: Option<Vec<CardFace>>,
     pub all_parts: Option<Vec<RelatedCard>>,
+    #[serde(default)]
     pub cmc: f32,
     #[serde(default)]
     pub colors: Vec<Color>,
     pub color_identity: Vec<Color>,
     pub color_indicator: Option<Vec<Color>>,
+    pub defense: Option<String>,
     pub edhrec_rank: Option<usize>,
+    #[serde(default)]
     pub foil: bool,
+    #[serde(default)]
+    pub game_changer: bool,
     pub hand_modifier: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
     pub layout: Layout,
-    // This map does not use the `Format` enum, since it would cause deserialization to fail when
-    // new formats are added by Scryfall.
-    pub legalities: HashMap<String, Legality>,
+    pub legalities: Legalities,
     pub life_modifier: Option<String>,
     pub loyalty: Option<String>,
     pub mana_cost: Option<String>,
     pub name: String,
+    #[serde(default)]
     pub nonfoil: bool,
     pub oracle_text: Option<String>,
     pub oversized: bool,
+    pub penny_rank: Option<usize>,
     pub power: Option<String>,
+    pub produced_mana: Option<Vec<String>>,
     pub reserved: bool,
     pub toughness: Option<String>,
     #[serde(default)]
     pub type_line: Option<String>,
     // Print Fields
     pub artist: Option<String>,
+    pub artist_ids: Option<Vec<Uuid>>,
+    pub attraction_lights: Option<Vec<u8>>,
+    #[serde(default)]
+    pub booster: bool,
     pub border_color: BorderColor,
+    pub card_back_id: Option<Uuid>,
     pub collector_number: String,
+    #[serde(default)]
+    pub content_warning: bool,
     pub digital: bool,
+    #[serde(default)]
+    pub finishes: Vec<Finish>,
+    pub flavor_name: Option<String>,
     pub flavor_text: Option<String>,
     #[serde(default)]
     pub frame_effects: Vec<FrameEffect>,
@@ -100,7 +144,8 @@ pub struct Card {
     pub games: Vec<Game>,
     pub highres_image: bool,
     pub illustration_id: Option<Uuid>,
-    pub image_uris: Option<HashMap<String, String>>,
+    pub image_status: Option<ImageStatus>,
+    pub image_uris: Option<ImageUris>,
     #[serde(default)]
     pub prices: Price,
     pub printed_name: Option<String>,
@@ -108,20 +153,34 @@ pub struct Card {
     pub printed_type_line: Option<String>,
     pub promo: bool,
     #[serde(default)]
+    pub promo_types: Vec<String>,
+    #[serde(default)]
     pub purchase_uris: HashMap<String, String>,
     pub rarity: Rarity,
     pub related_uris: HashMap<String, String>,
     pub released_at: NaiveDate,
     pub reprint: bool,
     pub scryfall_set_uri: String,
+    pub security_stamp: Option<SecurityStamp>,
+    pub set_id: Option<Uuid>,
     pub set_name: String,
     pub set_search_uri: Uri<List<Card>>,
+    pub set_type: Option<SetType>,
     pub set_uri: Uri<Set>,
     pub set: String,
     pub story_spotlight: bool,
+    #[serde(default)]
+    pub textless: bool,
+    #[serde(default)]
+    pub variation: bool,
+    pub variation_of: Option<Uuid>,
     pub watermark: Option<String>,
     #[serde(default)]
     pub preview: Preview,
+    /// The fields of the card that this crate doesn't model.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Card {
@@ -139,9 +198,20 @@ impl Card {
         note = "Scryfall is deprecating this endpoint on the 30/May/2020 in favour of the bulk endpoints"
     )]
     pub fn all() -> crate::Result<ListIter<Card>> {
-        let mut url = CARDS_URL.clone();
+        #[allow(deprecated)]
+        Card::all_with(ScryfallClient::global())
+    }
+
+    /// Same as [`all`](#method.all), but the request is made with the given
+    /// client.
+    #[deprecated(
+        since = "0.6.0",
+        note = "Scryfall is deprecating this endpoint on the 30/May/2020 in favour of the bulk endpoints"
+    )]
+    pub fn all_with(client: &ScryfallClient) -> crate::Result<ListIter<Card>> {
+        let mut url = client.endpoint(CARDS_PATH)?;
         url.query_pairs_mut().append_pair("page", "1");
-        Uri::from(url).fetch_iter()
+        Uri::from(url).fetch_iter_with(client)
     }
 
     /// Fetches a random card.
@@ -155,7 +225,13 @@ impl Card {
     /// }
     /// ```
     pub fn random() -> crate::Result<Card> {
-        Uri::from(CARDS_URL.join("random/")?).fetch()
+        Card::random_with(ScryfallClient::global())
+    }
+
+    /// Same as [`random`](#method.random), but the request is made with the
+    /// given client.
+    pub fn random_with(client: &ScryfallClient) -> crate::Result<Card> {
+        Uri::from(client.endpoint(CARDS_PATH)?.join("random/")?).fetch_with(client)
     }
 
     /// Returns a [`ListIter`] of the cards that match the search terms.
@@ -209,9 +285,18 @@ impl Card {
     /// };
     /// ```
     pub fn search<S: Search>(query: S) -> crate::Result<ListIter<Card>> {
-        let mut url = CARDS_URL.join("search/").unwrap();
+        Card::search_with(ScryfallClient::global(), query)
+    }
+
+    /// Same as [`search`](#method.search), but the request is made with the
+    /// given client.
+    pub fn search_with<S: Search>(
+        client: &ScryfallClient,
+        query: S,
+    ) -> crate::Result<ListIter<Card>> {
+        let mut url = client.endpoint(CARDS_PATH)?.join("search/").unwrap();
         url.set_query(Some(&query.to_query()));
-        Uri::from(url).fetch_iter()
+        Uri::from(url).fetch_iter_with(client)
     }
 
     /// Return a card with the exact name.
@@ -231,9 +316,15 @@ impl Card {
     /// assert!(Card::named("Name that doesn't exist").is_err())
     /// ```
     pub fn named(name: &str) -> crate::Result<Card> {
-        let mut url = CARDS_URL.join("named")?;
+        Card::named_with(ScryfallClient::global(), name)
+    }
+
+    /// Same as [`named`](#method.named), but the request is made with the given
+    /// client.
+    pub fn named_with(client: &ScryfallClient, name: &str) -> crate::Result<Card> {
+        let mut url = client.endpoint(CARDS_PATH)?.join("named")?;
         url.query_pairs_mut().append_pair("exact", name);
-        Uri::from(url).fetch()
+        Uri::from(url).fetch_with(client)
     }
 
     /// Return a card using the scryfall fuzzy finder.
@@ -247,9 +338,15 @@ impl Card {
     /// }
     /// ```
     pub fn named_fuzzy(query: &str) -> crate::Result<Card> {
-        let mut url = CARDS_URL.join("named")?;
+        Card::named_fuzzy_with(ScryfallClient::global(), query)
+    }
+
+    /// Same as [`named_fuzzy`](#method.named_fuzzy), but the request is made
+    /// with the given client.
+    pub fn named_fuzzy_with(client: &ScryfallClient, query: &str) -> crate::Result<Card> {
+        let mut url = client.endpoint(CARDS_PATH)?.join("named")?;
         url.query_pairs_mut().append_pair("fuzzy", query);
-        Uri::from(url).fetch()
+        Uri::from(url).fetch_with(client)
     }
 
     /// Fetch a card by its set and number.
@@ -263,7 +360,22 @@ impl Card {
     /// }
     /// ```
     pub fn set_and_number(set_code: &str, number: usize) -> crate::Result<Card> {
-        Uri::from(CARDS_URL.join(&format!("{}/{}", set_code, number))?).fetch()
+        Card::set_and_number_with(ScryfallClient::global(), set_code, number)
+    }
+
+    /// Same as [`set_and_number`](#method.set_and_number), but the request is
+    /// made with the given client.
+    pub fn set_and_number_with(
+        client: &ScryfallClient,
+        set_code: &str,
+        number: usize,
+    ) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join(&format!("{}/{}", set_code, number))?,
+        )
+        .fetch_with(client)
     }
 
     /// Fetch a card by its multiverse id.
@@ -277,12 +389,19 @@ impl Card {
     /// }
     /// ```
     pub fn multiverse(multiverse_id: usize) -> crate::Result<Card> {
+        Card::multiverse_with(ScryfallClient::global(), multiverse_id)
+    }
+
+    /// Same as [`multiverse`](#method.multiverse), but the request is made with
+    /// the given client.
+    pub fn multiverse_with(client: &ScryfallClient, multiverse_id: usize) -> crate::Result<Card> {
         Uri::from(
-            CARDS_URL
+            client
+                .endpoint(CARDS_PATH)?
                 .join("multiverse/")?
                 .join(&multiverse_id.to_string())?,
         )
-        .fetch()
+        .fetch_with(client)
     }
 
     /// Fetch a card by its mtgo id.
@@ -296,7 +415,19 @@ impl Card {
     /// }
     /// ```
     pub fn mtgo(mtgo_id: usize) -> crate::Result<Card> {
-        Uri::from(CARDS_URL.join("mtgo/")?.join(&mtgo_id.to_string())?).fetch()
+        Card::mtgo_with(ScryfallClient::global(), mtgo_id)
+    }
+
+    /// Same as [`mtgo`](#method.mtgo), but the request is made with the given
+    /// client.
+    pub fn mtgo_with(client: &ScryfallClient, mtgo_id: usize) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join("mtgo/")?
+                .join(&mtgo_id.to_string())?,
+        )
+        .fetch_with(client)
     }
 
     /// Fetch a card by its arena id.
@@ -310,7 +441,19 @@ impl Card {
     /// }
     /// ```
     pub fn arena(arena_id: usize) -> crate::Result<Card> {
-        Uri::from(CARDS_URL.join("arena/")?.join(&arena_id.to_string())?).fetch()
+        Card::arena_with(ScryfallClient::global(), arena_id)
+    }
+
+    /// Same as [`arena`](#method.arena), but the request is made with the given
+    /// client.
+    pub fn arena_with(client: &ScryfallClient, arena_id: usize) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join("arena/")?
+                .join(&arena_id.to_string())?,
+        )
+        .fetch_with(client)
     }
 
     /// Fetch a card by its tcgplayer id.
@@ -324,12 +467,19 @@ impl Card {
     /// }
     /// ```
     pub fn tcgplayer(tcgplayer_id: usize) -> crate::Result<Card> {
+        Card::tcgplayer_with(ScryfallClient::global(), tcgplayer_id)
+    }
+
+    /// Same as [`tcgplayer`](#method.tcgplayer), but the request is made with
+    /// the given client.
+    pub fn tcgplayer_with(client: &ScryfallClient, tcgplayer_id: usize) -> crate::Result<Card> {
         Uri::from(
-            CARDS_URL
+            client
+                .endpoint(CARDS_PATH)?
                 .join("tcgplayer/")?
                 .join(&tcgplayer_id.to_string())?,
         )
-        .fetch()
+        .fetch_with(client)
     }
 
     /// Fetch a card by its Uuid.
@@ -342,7 +492,986 @@ impl Card {
     ///     Err(e) => panic!("{:?}", e),
     /// }
     /// ```
+    #[allow(clippy::self_named_constructors)]
     pub fn card(scryfall_id: Uuid) -> crate::Result<Card> {
-        Uri::from(CARDS_URL.join(&scryfall_id.to_string())?).fetch()
+        Card::card_with(ScryfallClient::global(), scryfall_id)
+    }
+
+    /// Same as [`card`](#method.card), but the request is made with the given
+    /// client.
+    pub fn card_with(client: &ScryfallClient, scryfall_id: Uuid) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join(&scryfall_id.to_string())?,
+        )
+        .fetch_with(client)
+    }
+
+    /// Parses the card's mana cost, or returns `None` if it doesn't have one.
+    ///
+    /// The mana costs of cards with several faces are joined with `//`. In
+    /// that case the symbols of every face are combined into a single cost.
+    /// Use [`CardFace::parse_mana_cost`] to get the cost of each face.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let cost = Card::named("Fire // Ice")
+    ///     .unwrap()
+    ///     .parse_mana_cost()
+    ///     .unwrap()
+    ///     .unwrap();
+    /// assert_eq!(cost.to_string(), "{1}{R}{1}{U}");
+    /// assert_eq!(cost.cmc(), 4.0);
+    /// ```
+    pub fn parse_mana_cost(&self) -> crate::Result<Option<ManaCost>> {
+        let mana_cost = match &self.mana_cost {
+            Some(mana_cost) => mana_cost,
+            None => return Ok(None),
+        };
+        let mut symbols = Vec::new();
+        for face in mana_cost.split("//") {
+            symbols.extend(face.parse::<ManaCost>()?.into_symbols());
+        }
+        Ok(Some(ManaCost::from_symbols(symbols)))
+    }
+
+    /// Parses the card's type line, or returns `None` if it doesn't have one.
+    ///
+    /// Cards whose faces are printed on both sides, like those with the
+    /// `reversible_card` layout, only have type lines on their faces. These are
+    /// combined into one.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let type_line = Card::named("Delver of Secrets")
+    ///     .unwrap()
+    ///     .parse_type_line()
+    ///     .unwrap()
+    ///     .unwrap();
+    /// assert_eq!(type_line.faces().len(), 2);
+    /// assert!(type_line.faces()[1].has_subtype("Insect"));
+    /// ```
+    pub fn parse_type_line(&self) -> crate::Result<Option<TypeLine>> {
+        if let Some(type_line) = &self.type_line {
+            return type_line.parse().map(Some);
+        }
+        let faces = self
+            .card_faces
+            .iter()
+            .flatten()
+            .filter_map(|face| face.type_line.as_deref())
+            .collect::<Vec<_>>();
+        if faces.is_empty() {
+            return Ok(None);
+        }
+        faces.join(" // ").parse().map(Some)
+    }
+
+    /// The image URIs of one face of the card.
+    ///
+    /// Cards with a single image, including split and flip cards, have their
+    /// image URIs on the card itself, and only have a front face. The images
+    /// of double-faced cards are on each of their faces.
+    pub fn face_image_uris(&self, face: ImageFace) -> Option<&ImageUris> {
+        match (&self.image_uris, face) {
+            (Some(image_uris), ImageFace::Front) => Some(image_uris),
+            (Some(_), ImageFace::Back) => None,
+            (None, face) => self
+                .card_faces
+                .as_ref()?
+                .get(face.index())?
+                .image_uris
+                .as_ref(),
+        }
+    }
+
+    /// Downloads the image of one face of the card, in the given size.
+    ///
+    /// The image is downloaded from the card's image URIs. If it doesn't have
+    /// them, it's requested through the API instead, with
+    /// `/cards/:id?format=image`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, ImageFace, ImageSize};
+    /// let card = Card::named("Delver of Secrets").unwrap();
+    /// let image = card
+    ///     .download_image(ImageFace::Back, ImageSize::Small)
+    ///     .unwrap();
+    /// assert!(image.starts_with(b"\xFF\xD8"));
+    /// ```
+    pub fn download_image(&self, face: ImageFace, size: ImageSize) -> crate::Result<Vec<u8>> {
+        self.download_image_with(ScryfallClient::global(), face, size)
+    }
+
+    /// Same as [`download_image`](#method.download_image), but the request is
+    /// made with the given client.
+    pub fn download_image_with(
+        &self,
+        client: &ScryfallClient,
+        face: ImageFace,
+        size: ImageSize,
+    ) -> crate::Result<Vec<u8>> {
+        let mut image = Vec::new();
+        self.download_image_to_with(client, face, size, &mut image)?;
+        Ok(image)
+    }
+
+    /// Same as [`download_image`](#method.download_image), but the image is
+    /// written to `writer` as it's downloaded. Returns the number of bytes
+    /// written.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use std::fs::File;
+    ///
+    /// use scryfall::card::{Card, ImageFace, ImageSize};
+    /// let card = Card::named("Lightning Bolt").unwrap();
+    /// let file = File::create("lightning_bolt.png").unwrap();
+    /// card.download_image_to(ImageFace::Front, ImageSize::Png, file)
+    ///     .unwrap();
+    /// ```
+    pub fn download_image_to(
+        &self,
+        face: ImageFace,
+        size: ImageSize,
+        writer: impl Write,
+    ) -> crate::Result<u64> {
+        self.download_image_to_with(ScryfallClient::global(), face, size, writer)
+    }
+
+    /// Same as [`download_image_to`](#method.download_image_to), but the
+    /// request is made with the given client.
+    pub fn download_image_to_with(
+        &self,
+        client: &ScryfallClient,
+        face: ImageFace,
+        size: ImageSize,
+        mut writer: impl Write,
+    ) -> crate::Result<u64> {
+        let url = self.image_url(client, face, size)?;
+        let mut reader = client.get(&url)?.into_reader();
+        Ok(io::copy(&mut reader, &mut writer)?)
+    }
+
+    /// The URL of the image of one face of the card, either from its image
+    /// URIs or from the API.
+    fn image_url(
+        &self,
+        client: &ScryfallClient,
+        face: ImageFace,
+        size: ImageSize,
+    ) -> crate::Result<Url> {
+        if let Some(image_uris) = self.face_image_uris(face) {
+            return Ok(image_uris.get(size).clone());
+        }
+        let mut url = client.endpoint(CARDS_PATH)?.join(&self.id.to_string())?;
+        url.query_pairs_mut()
+            .append_pair("format", "image")
+            .append_pair("version", &size.to_string());
+        if face == ImageFace::Back {
+            url.query_pairs_mut().append_pair("face", "back");
+        }
+        Ok(url)
+    }
+
+    /// Returns a [`Catalog`] of up to 20 full English card names that could be
+    /// autocompletions of the given string. Funny, digital and token cards are
+    /// excluded, see
+    /// [`autocomplete_including_extras`](#method.autocomplete_including_extras)
+    /// to include them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Card;
+    /// let names = Card::autocomplete("thor").unwrap().data;
+    /// assert!(names.contains(&"Thorn Elemental".to_string()));
+    /// ```
+    pub fn autocomplete(query: &str) -> crate::Result<Catalog> {
+        Card::autocomplete_with(ScryfallClient::global(), query)
+    }
+
+    /// Same as [`autocomplete`](#method.autocomplete), but the request is made
+    /// with the given client.
+    pub fn autocomplete_with(client: &ScryfallClient, query: &str) -> crate::Result<Catalog> {
+        Uri::from(autocomplete_url(client, query, false)?).fetch_with(client)
+    }
+
+    /// Same as [`autocomplete`](#method.autocomplete), but extra cards, such
+    /// as funny, digital and token cards, are included.
+    pub fn autocomplete_including_extras(query: &str) -> crate::Result<Catalog> {
+        Card::autocomplete_including_extras_with(ScryfallClient::global(), query)
+    }
+
+    /// Same as
+    /// [`autocomplete_including_extras`](#method.autocomplete_including_extras),
+    /// but the request is made with the given client.
+    pub fn autocomplete_including_extras_with(
+        client: &ScryfallClient,
+        query: &str,
+    ) -> crate::Result<Catalog> {
+        Uri::from(autocomplete_url(client, query, true)?).fetch_with(client)
+    }
+
+    /// Fetches the cards matching a list of identifiers, with as few requests
+    /// as possible.
+    ///
+    /// Scryfall accepts up to 75 identifiers per request, so longer lists are
+    /// split into several requests. Identifiers that don't match any card are
+    /// reported in [`Collection::not_found`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{Card, CardIdentifier};
+    ///
+    /// let collection = Card::collection(&[
+    ///     CardIdentifier::Name("Lightning Bolt".to_string()),
+    ///     CardIdentifier::SetAndNumber("war".to_string(), "123".to_string()),
+    ///     CardIdentifier::Name("Not a real card".to_string()),
+    /// ])
+    /// .unwrap();
+    /// let names = collection
+    ///     .cards
+    ///     .iter()
+    ///     .map(|card| card.name.as_str())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(names, ["Lightning Bolt", "Demolish"]);
+    /// assert_eq!(
+    ///     collection.not_found,
+    ///     [CardIdentifier::Name("Not a real card".to_string())]
+    /// );
+    /// ```
+    pub fn collection(identifiers: &[CardIdentifier]) -> crate::Result<Collection> {
+        Card::collection_with(ScryfallClient::global(), identifiers)
+    }
+
+    /// Same as [`collection`](#method.collection), but the requests are made
+    /// with the given client.
+    pub fn collection_with(
+        client: &ScryfallClient,
+        identifiers: &[CardIdentifier],
+    ) -> crate::Result<Collection> {
+        let url = client.endpoint(CARDS_PATH)?.join("collection")?;
+        let mut collection = Collection {
+            cards: Vec::with_capacity(identifiers.len()),
+            not_found: Vec::new(),
+        };
+        for identifiers in identifiers.chunks(MAX_IDENTIFIERS) {
+            collection.extend(client.post(&url, &CollectionRequest { identifiers })?);
+        }
+        Ok(collection)
+    }
+}
+
+#[cfg(feature = "async")]
+impl Card {
+    /// Asynchronous version of [`random`](#method.random).
+    pub async fn random_async() -> crate::Result<Card> {
+        Card::random_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`random_async`](#method.random_async), but the request is made
+    /// with the given client.
+    pub async fn random_async_with(client: &ScryfallClient) -> crate::Result<Card> {
+        Uri::from(client.endpoint(CARDS_PATH)?.join("random/")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`search`](#method.search).
+    pub async fn search_async<S: Search>(query: S) -> crate::Result<ListStream<Card>> {
+        Card::search_async_with(ScryfallClient::global(), query).await
+    }
+
+    /// Same as [`search_async`](#method.search_async), but the request is made
+    /// with the given client.
+    pub async fn search_async_with<S: Search>(
+        client: &ScryfallClient,
+        query: S,
+    ) -> crate::Result<ListStream<Card>> {
+        let mut url = client.endpoint(CARDS_PATH)?.join("search/").unwrap();
+        url.set_query(Some(&query.to_query()));
+        Uri::from(url).fetch_stream_with(client).await
+    }
+
+    /// Asynchronous version of [`named`](#method.named).
+    pub async fn named_async(name: &str) -> crate::Result<Card> {
+        Card::named_async_with(ScryfallClient::global(), name).await
+    }
+
+    /// Same as [`named_async`](#method.named_async), but the request is made
+    /// with the given client.
+    pub async fn named_async_with(client: &ScryfallClient, name: &str) -> crate::Result<Card> {
+        let mut url = client.endpoint(CARDS_PATH)?.join("named")?;
+        url.query_pairs_mut().append_pair("exact", name);
+        Uri::from(url).fetch_async_with(client).await
+    }
+
+    /// Asynchronous version of [`named_fuzzy`](#method.named_fuzzy).
+    pub async fn named_fuzzy_async(query: &str) -> crate::Result<Card> {
+        Card::named_fuzzy_async_with(ScryfallClient::global(), query).await
+    }
+
+    /// Same as [`named_fuzzy_async`](#method.named_fuzzy_async), but the
+    /// request is made with the given client.
+    pub async fn named_fuzzy_async_with(
+        client: &ScryfallClient,
+        query: &str,
+    ) -> crate::Result<Card> {
+        let mut url = client.endpoint(CARDS_PATH)?.join("named")?;
+        url.query_pairs_mut().append_pair("fuzzy", query);
+        Uri::from(url).fetch_async_with(client).await
+    }
+
+    /// Asynchronous version of [`set_and_number`](#method.set_and_number).
+    pub async fn set_and_number_async(set_code: &str, number: usize) -> crate::Result<Card> {
+        Card::set_and_number_async_with(ScryfallClient::global(), set_code, number).await
+    }
+
+    /// Same as [`set_and_number_async`](#method.set_and_number_async), but the
+    /// request is made with the given client.
+    pub async fn set_and_number_async_with(
+        client: &ScryfallClient,
+        set_code: &str,
+        number: usize,
+    ) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join(&format!("{}/{}", set_code, number))?,
+        )
+        .fetch_async_with(client)
+        .await
+    }
+
+    /// Asynchronous version of [`multiverse`](#method.multiverse).
+    pub async fn multiverse_async(multiverse_id: usize) -> crate::Result<Card> {
+        Card::multiverse_async_with(ScryfallClient::global(), multiverse_id).await
+    }
+
+    /// Same as [`multiverse_async`](#method.multiverse_async), but the request
+    /// is made with the given client.
+    pub async fn multiverse_async_with(
+        client: &ScryfallClient,
+        multiverse_id: usize,
+    ) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join("multiverse/")?
+                .join(&multiverse_id.to_string())?,
+        )
+        .fetch_async_with(client)
+        .await
+    }
+
+    /// Asynchronous version of [`mtgo`](#method.mtgo).
+    pub async fn mtgo_async(mtgo_id: usize) -> crate::Result<Card> {
+        Card::mtgo_async_with(ScryfallClient::global(), mtgo_id).await
+    }
+
+    /// Same as [`mtgo_async`](#method.mtgo_async), but the request is made with
+    /// the given client.
+    pub async fn mtgo_async_with(client: &ScryfallClient, mtgo_id: usize) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join("mtgo/")?
+                .join(&mtgo_id.to_string())?,
+        )
+        .fetch_async_with(client)
+        .await
+    }
+
+    /// Asynchronous version of [`arena`](#method.arena).
+    pub async fn arena_async(arena_id: usize) -> crate::Result<Card> {
+        Card::arena_async_with(ScryfallClient::global(), arena_id).await
+    }
+
+    /// Same as [`arena_async`](#method.arena_async), but the request is made
+    /// with the given client.
+    pub async fn arena_async_with(client: &ScryfallClient, arena_id: usize) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join("arena/")?
+                .join(&arena_id.to_string())?,
+        )
+        .fetch_async_with(client)
+        .await
+    }
+
+    /// Asynchronous version of [`tcgplayer`](#method.tcgplayer).
+    pub async fn tcgplayer_async(tcgplayer_id: usize) -> crate::Result<Card> {
+        Card::tcgplayer_async_with(ScryfallClient::global(), tcgplayer_id).await
+    }
+
+    /// Same as [`tcgplayer_async`](#method.tcgplayer_async), but the request is
+    /// made with the given client.
+    pub async fn tcgplayer_async_with(
+        client: &ScryfallClient,
+        tcgplayer_id: usize,
+    ) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join("tcgplayer/")?
+                .join(&tcgplayer_id.to_string())?,
+        )
+        .fetch_async_with(client)
+        .await
+    }
+
+    /// Asynchronous version of [`card`](#method.card).
+    pub async fn card_async(scryfall_id: Uuid) -> crate::Result<Card> {
+        Card::card_async_with(ScryfallClient::global(), scryfall_id).await
+    }
+
+    /// Same as [`card_async`](#method.card_async), but the request is made with
+    /// the given client.
+    pub async fn card_async_with(
+        client: &ScryfallClient,
+        scryfall_id: Uuid,
+    ) -> crate::Result<Card> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join(&scryfall_id.to_string())?,
+        )
+        .fetch_async_with(client)
+        .await
+    }
+
+    /// Asynchronous version of [`autocomplete`](#method.autocomplete).
+    pub async fn autocomplete_async(query: &str) -> crate::Result<Catalog> {
+        Card::autocomplete_async_with(ScryfallClient::global(), query).await
+    }
+
+    /// Same as [`autocomplete_async`](#method.autocomplete_async), but the
+    /// request is made with the given client.
+    pub async fn autocomplete_async_with(
+        client: &ScryfallClient,
+        query: &str,
+    ) -> crate::Result<Catalog> {
+        Uri::from(autocomplete_url(client, query, false)?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of
+    /// [`autocomplete_including_extras`](#method.autocomplete_including_extras).
+    pub async fn autocomplete_including_extras_async(query: &str) -> crate::Result<Catalog> {
+        Card::autocomplete_including_extras_async_with(ScryfallClient::global(), query).await
+    }
+
+    /// Same as
+    /// [`autocomplete_including_extras_async`](#method.autocomplete_including_extras_async),
+    /// but the request is made with the given client.
+    pub async fn autocomplete_including_extras_async_with(
+        client: &ScryfallClient,
+        query: &str,
+    ) -> crate::Result<Catalog> {
+        Uri::from(autocomplete_url(client, query, true)?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`collection`](#method.collection).
+    pub async fn collection_async(identifiers: &[CardIdentifier]) -> crate::Result<Collection> {
+        Card::collection_async_with(ScryfallClient::global(), identifiers).await
+    }
+
+    /// Same as [`collection_async`](#method.collection_async), but the
+    /// requests are made with the given client.
+    pub async fn collection_async_with(
+        client: &ScryfallClient,
+        identifiers: &[CardIdentifier],
+    ) -> crate::Result<Collection> {
+        let url = client.endpoint(CARDS_PATH)?.join("collection")?;
+        let mut collection = Collection {
+            cards: Vec::with_capacity(identifiers.len()),
+            not_found: Vec::new(),
+        };
+        for identifiers in identifiers.chunks(MAX_IDENTIFIERS) {
+            let response = client
+                .post_async(&url, &CollectionRequest { identifiers })
+                .await?;
+            collection.extend(response);
+        }
+        Ok(collection)
+    }
+}
+
+#[cfg(feature = "async")]
+impl Card {
+    /// Asynchronous version of [`download_image`](#method.download_image).
+    pub async fn download_image_async(
+        &self,
+        face: ImageFace,
+        size: ImageSize,
+    ) -> crate::Result<Vec<u8>> {
+        self.download_image_async_with(ScryfallClient::global(), face, size)
+            .await
+    }
+
+    /// Same as [`download_image_async`](#method.download_image_async), but the
+    /// request is made with the given client.
+    pub async fn download_image_async_with(
+        &self,
+        client: &ScryfallClient,
+        face: ImageFace,
+        size: ImageSize,
+    ) -> crate::Result<Vec<u8>> {
+        let url = self.image_url(client, face, size)?;
+        let response = client.get_async(&url).await?;
+        let image = response
+            .bytes()
+            .await
+            .map_err(|e| crate::error::Error::ReqwestError(e, url.to_string()))?;
+        Ok(image.to_vec())
+    }
+}
+
+/// The URL of the autocomplete endpoint for the given query.
+fn autocomplete_url(
+    client: &ScryfallClient,
+    query: &str,
+    include_extras: bool,
+) -> crate::Result<Url> {
+    let mut url = client.endpoint(CARDS_PATH)?.join("autocomplete")?;
+    url.query_pairs_mut().append_pair("q", query);
+    if include_extras {
+        url.query_pairs_mut().append_pair("include_extras", "true");
+    }
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::{TestResponse, TestServer};
+
+    const LIGHTNING_BOLT: &str = include_str!("../tests/fixtures/lightning_bolt.json");
+
+    #[test]
+    fn unseen_enum_values() {
+        let card =
+            serde_json::from_str::<Card>(include_str!("../tests/fixtures/unseen_values.json"))
+                .unwrap();
+        let other = |name: &str| name.to_string();
+        assert_eq!(card.layout, Layout::Other(other("hologram")));
+        assert_eq!(
+            card.frame_effects,
+            [
+                FrameEffect::Legendary,
+                FrameEffect::Other(other("glitterfoil"))
+            ]
+        );
+        assert_eq!(card.games, [Game::Paper, Game::Other(other("holodeck"))]);
+        assert_eq!(card.border_color, BorderColor::Other(other("neon")));
+        assert_eq!(card.frame, Frame::Other(other("2030")));
+        assert_eq!(card.rarity, Rarity::Other(other("ultra")));
+        let component = &card.all_parts.as_ref().unwrap()[0].component;
+        assert_eq!(*component, Component::Other(other("sidekick")));
+
+        assert_eq!(card.layout.to_string(), "hologram");
+        assert_eq!(card.rarity.to_string(), "ultra");
+        assert_eq!(component.to_string(), "sidekick");
+
+        let json = serde_json::to_value(&card).unwrap();
+        assert_eq!(
+            json["frame_effects"],
+            serde_json::json!(["legendary", "glitterfoil"])
+        );
+        assert_eq!(json["frame"], "2030");
+        assert_eq!(serde_json::from_value::<Card>(json).unwrap(), card);
+    }
+
+    // The fixtures are written by hand in the shape of Scryfall's responses,
+    // with made up ids. `layouts_from_api` checks the real ones.
+    #[test]
+    fn deserialize_layouts() {
+        let layouts = [
+            (
+                Layout::Normal,
+                include_str!("../tests/fixtures/lightning_bolt.json"),
+            ),
+            (
+                Layout::Split,
+                include_str!("../tests/fixtures/layouts/split.json"),
+            ),
+            (
+                Layout::Flip,
+                include_str!("../tests/fixtures/layouts/flip.json"),
+            ),
+            (
+                Layout::Transform,
+                include_str!("../tests/fixtures/layouts/transform.json"),
+            ),
+            (
+                Layout::ModalDfc,
+                include_str!("../tests/fixtures/layouts/modal_dfc.json"),
+            ),
+            (
+                Layout::Adventure,
+                include_str!("../tests/fixtures/layouts/adventure.json"),
+            ),
+            (
+                Layout::Meld,
+                include_str!("../tests/fixtures/layouts/meld.json"),
+            ),
+            (
+                Layout::ReversibleCard,
+                include_str!("../tests/fixtures/layouts/reversible_card.json"),
+            ),
+            (
+                Layout::Token,
+                include_str!("../tests/fixtures/layouts/token.json"),
+            ),
+            (
+                Layout::Leveler,
+                include_str!("../tests/fixtures/layouts/leveler.json"),
+            ),
+            (
+                Layout::Saga,
+                include_str!("../tests/fixtures/layouts/saga.json"),
+            ),
+            (
+                Layout::Class,
+                include_str!("../tests/fixtures/layouts/class.json"),
+            ),
+            (
+                Layout::Case,
+                include_str!("../tests/fixtures/layouts/case.json"),
+            ),
+            (
+                Layout::Mutate,
+                include_str!("../tests/fixtures/layouts/mutate.json"),
+            ),
+            (
+                Layout::Prototype,
+                include_str!("../tests/fixtures/layouts/prototype.json"),
+            ),
+            (
+                Layout::Battle,
+                include_str!("../tests/fixtures/layouts/battle.json"),
+            ),
+            (
+                Layout::Planar,
+                include_str!("../tests/fixtures/layouts/planar.json"),
+            ),
+            (
+                Layout::Scheme,
+                include_str!("../tests/fixtures/layouts/scheme.json"),
+            ),
+            (
+                Layout::Vanguard,
+                include_str!("../tests/fixtures/layouts/vanguard.json"),
+            ),
+            (
+                Layout::Emblem,
+                include_str!("../tests/fixtures/layouts/emblem.json"),
+            ),
+            (
+                Layout::DoubleFacedToken,
+                include_str!("../tests/fixtures/layouts/double_faced_token.json"),
+            ),
+            (
+                Layout::ArtSeries,
+                include_str!("../tests/fixtures/layouts/art_series.json"),
+            ),
+            (
+                Layout::Host,
+                include_str!("../tests/fixtures/layouts/host.json"),
+            ),
+            (
+                Layout::Augment,
+                include_str!("../tests/fixtures/layouts/augment.json"),
+            ),
+        ];
+        let faced = [
+            Layout::Split,
+            Layout::Flip,
+            Layout::Transform,
+            Layout::ModalDfc,
+            Layout::Adventure,
+            Layout::ReversibleCard,
+            Layout::Battle,
+            Layout::DoubleFacedToken,
+            Layout::ArtSeries,
+        ];
+        let double_faced = [
+            Layout::Transform,
+            Layout::ModalDfc,
+            Layout::ReversibleCard,
+            Layout::Battle,
+            Layout::DoubleFacedToken,
+            Layout::ArtSeries,
+        ];
+        for (layout, json) in layouts.iter() {
+            let card = serde_json::from_str::<Card>(json).unwrap();
+            assert_eq!(card.layout, *layout);
+            assert_eq!(card.lang, Language::English);
+            let faces = card.card_faces.as_deref().unwrap_or_default();
+            assert_eq!(faces.len(), if faced.contains(layout) { 2 } else { 0 });
+            match layout {
+                Layout::Meld => {
+                    let parts = card.all_parts.as_ref().unwrap();
+                    assert_eq!(parts[2].component, Component::MeldResult);
+                },
+                Layout::Token | Layout::Emblem | Layout::DoubleFacedToken => {
+                    assert!(!card.booster);
+                    assert!(!card.legalities.is_legal(crate::format::Format::Vintage));
+                },
+                Layout::Battle => assert_eq!(faces[0].defense.as_deref(), Some("3")),
+                Layout::Vanguard => {
+                    assert_eq!(card.hand_modifier.as_deref(), Some("-1"));
+                    assert_eq!(card.life_modifier.as_deref(), Some("+15"));
+                },
+                Layout::Planar | Layout::Scheme => assert!(card.oversized),
+                _ => {},
+            }
+            if double_faced.contains(layout) {
+                assert!(card.image_uris.is_none());
+                assert!(faces.iter().all(|face| face.image_uris.is_some()));
+            }
+            assert_eq!(
+                serde_json::from_value::<Card>(serde_json::to_value(&card).unwrap()).unwrap(),
+                card
+            );
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn layouts_from_api() {
+        use crate::card_searcher::{SearchBuilder, StringParam};
+
+        let cards = [
+            (Layout::Normal, "Lightning Bolt"),
+            (Layout::Split, "Fire // Ice"),
+            (Layout::Flip, "Bushi Tenderfoot // Kenzo the Hardhearted"),
+            (
+                Layout::Transform,
+                "Delver of Secrets // Insectile Aberration",
+            ),
+            (Layout::ModalDfc, "Valakut Awakening // Valakut Stoneforge"),
+            (Layout::Adventure, "Bonecrusher Giant // Stomp"),
+            (Layout::Meld, "Bruna, the Fading Light"),
+            (Layout::Leveler, "Student of Warfare"),
+            (Layout::Saga, "History of Benalia"),
+            (Layout::Class, "Druid Class"),
+            (Layout::Case, "Case of the Locked Hothouse"),
+            (Layout::Mutate, "Gemrazer"),
+            (Layout::Prototype, "Arcane Proxy"),
+            (Layout::Battle, "Invasion of Zendikar // Awakened Skyclave"),
+            (Layout::Planar, "Tazeem"),
+            (Layout::Scheme, "All in Good Time"),
+            (Layout::Vanguard, "Eladamri"),
+            (Layout::Emblem, "Chandra, Torch of Defiance Emblem"),
+            (Layout::Host, "Adorable Kitten"),
+        ];
+        for (layout, name) in cards.iter() {
+            let card = SearchBuilder::new()
+                .including_extras()
+                .param(StringParam::ExactName(name.to_string()))
+                .search()
+                .unwrap_or_else(|e| panic!("Could not find {} - {}", name, e))
+                .next()
+                .unwrap();
+            assert_eq!(card.layout, *layout, "{}", name);
+        }
+    }
+
+    #[test]
+    fn reversible_cards_have_face_oracle_ids() {
+        let card = serde_json::from_str::<Card>(include_str!(
+            "../tests/fixtures/layouts/reversible_card.json"
+        ))
+        .unwrap();
+        assert_eq!(card.oracle_id, None);
+        assert_eq!(card.cmc, 0.0);
+        assert_eq!(card.mana_cost, None);
+        assert_eq!(card.finishes, [Finish::Foil]);
+        let faces = card.card_faces.unwrap();
+        assert!(faces[0].oracle_id.is_some());
+        assert_eq!(faces[0].oracle_id, faces[1].oracle_id);
+        assert_eq!(faces[0].cmc, Some(5.0));
+        assert_eq!(faces[0].layout, Some(Layout::Normal));
+    }
+
+    #[test]
+    fn new_card_fields() {
+        let card = serde_json::from_str::<Card>(LIGHTNING_BOLT).unwrap();
+        assert_eq!(card.keywords, Vec::<String>::new());
+        assert!(card.cardmarket_id.is_some());
+        assert!(card.card_back_id.is_some());
+        assert_eq!(card.artist_ids.as_ref().map(Vec::len), Some(1));
+        assert_eq!(card.set_type, Some(SetType::Masters));
+        assert!(!card.finishes.is_empty());
+
+        let mut json = serde_json::from_str::<serde_json::Value>(LIGHTNING_BOLT).unwrap();
+        json["lang"] = "ph".into();
+        json["security_stamp"] = "oval".into();
+        json["image_status"] = "highres_scan".into();
+        json["produced_mana"] = serde_json::json!(["R", "C"]);
+        json["attraction_lights"] = serde_json::json!([2, 4, 6]);
+        json["finishes"] = serde_json::json!(["nonfoil", "galaxy"]);
+        let card = serde_json::from_value::<Card>(json).unwrap();
+        assert_eq!(card.lang, Language::Phyrexian);
+        assert_eq!(card.security_stamp, Some(SecurityStamp::Oval));
+        assert_eq!(card.image_status, Some(ImageStatus::HighresScan));
+        assert_eq!(card.produced_mana.unwrap(), ["R", "C"]);
+        assert_eq!(card.attraction_lights.unwrap(), [2, 4, 6]);
+        assert_eq!(
+            card.finishes,
+            [Finish::Nonfoil, Finish::Other("galaxy".to_string())]
+        );
+    }
+
+    #[test]
+    fn old_dumps_still_parse() {
+        let mut json = serde_json::from_str::<serde_json::Value>(LIGHTNING_BOLT).unwrap();
+        let new_fields = [
+            "cardmarket_id",
+            "keywords",
+            "finishes",
+            "variation",
+            "set_id",
+            "set_type",
+            "card_back_id",
+            "artist_ids",
+            "textless",
+            "booster",
+            "penny_rank",
+            "image_status",
+            "foil",
+            "nonfoil",
+        ];
+        for key in new_fields.iter() {
+            json.as_object_mut().unwrap().remove(*key);
+        }
+        let card = serde_json::from_value::<Card>(json).unwrap();
+        assert!(card.keywords.is_empty() && card.finishes.is_empty());
+        assert_eq!(card.set_type, None);
+        assert!(!card.booster);
+        assert_eq!(card.name, "Lightning Bolt");
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn extra_fields_round_trip() {
+        let mut json = serde_json::from_str::<serde_json::Value>(include_str!(
+            "../tests/fixtures/layouts/meld.json"
+        ))
+        .unwrap();
+        json["flavor_palette"] = serde_json::json!({"frame": "amber"});
+        json["all_parts"][0]["shimmer"] = true.into();
+        json["card_faces"] = serde_json::json!([{
+            "object": "card_face",
+            "name": "Bruna, the Fading Light",
+            "mana_cost": "{5}{W}{W}",
+            "face_ornament": 3,
+        }]);
+        let card = serde_json::from_value::<Card>(json.clone()).unwrap();
+        assert_eq!(card.extra["object"], "card");
+        assert_eq!(card.extra["flavor_palette"]["frame"], "amber");
+        assert!(!card.extra.contains_key("name"));
+        assert_eq!(card.all_parts.as_ref().unwrap()[0].extra["shimmer"], true);
+        assert_eq!(
+            card.card_faces.as_ref().unwrap()[0].extra["face_ornament"],
+            3
+        );
+
+        // Fields Scryfall left out come back as `null`, so only check that every
+        // field that was sent comes back unchanged.
+        fn assert_kept(sent: &serde_json::Value, round_trip: &serde_json::Value) {
+            match (sent, round_trip) {
+                (serde_json::Value::Object(sent), serde_json::Value::Object(round_trip)) => {
+                    for (key, value) in sent {
+                        assert_kept(value, &round_trip[key]);
+                    }
+                },
+                (serde_json::Value::Array(sent), serde_json::Value::Array(round_trip)) => {
+                    assert_eq!(sent.len(), round_trip.len());
+                    for (sent, round_trip) in sent.iter().zip(round_trip) {
+                        assert_kept(sent, round_trip);
+                    }
+                },
+                _ => assert_eq!(sent, round_trip),
+            }
+        }
+        assert_kept(&json, &serde_json::to_value(&card).unwrap());
+    }
+
+    #[test]
+    fn download_images() {
+        let server = TestServer::new(vec![
+            TestResponse::new(200, "front"),
+            TestResponse::new(200, "back"),
+        ]);
+        let client = server.client();
+
+        let mut card = serde_json::from_str::<Card>(LIGHTNING_BOLT).unwrap();
+        let mut image_uris = card.image_uris.take().unwrap();
+        image_uris.png = server.url().join("png/front.png").unwrap();
+        card.image_uris = Some(image_uris);
+        assert!(card.face_image_uris(ImageFace::Back).is_none());
+        let image = card
+            .download_image_with(&client, ImageFace::Front, ImageSize::Png)
+            .unwrap();
+        assert_eq!(image, b"front");
+
+        card.image_uris = None;
+        let mut image = Vec::new();
+        let written = card
+            .download_image_to_with(&client, ImageFace::Back, ImageSize::ArtCrop, &mut image)
+            .unwrap();
+        assert_eq!((written, image.as_slice()), (4, &b"back"[..]));
+
+        let paths = server
+            .requests()
+            .into_iter()
+            .map(|r| r.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "/png/front.png".to_string(),
+                format!("/cards/{}?format=image&version=art_crop&face=back", card.id),
+            ]
+        );
+    }
+
+    #[test]
+    fn autocomplete() {
+        const CATALOG: &str = include_str!("../tests/fixtures/autocomplete.json");
+        let server = TestServer::new(vec![
+            TestResponse::json(CATALOG),
+            TestResponse::json(CATALOG),
+        ]);
+        let client = server.client();
+
+        let catalog = Card::autocomplete_with(&client, "thor e").unwrap();
+        assert_eq!(catalog.uri, None);
+        assert_eq!(catalog.data.len(), 20);
+        assert_eq!(catalog.data[0], "Thorn Elemental");
+        Card::autocomplete_including_extras_with(&client, "thor").unwrap();
+
+        let paths = server
+            .requests()
+            .into_iter()
+            .map(|r| r.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            [
+                "/cards/autocomplete?q=thor+e",
+                "/cards/autocomplete?q=thor&include_extras=true",
+            ]
+        );
     }
 }
@@ -2,22 +2,20 @@
 use serde::{Deserialize, Serialize};
 
 /// Enum defining the colors a mtg card border can have.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug, Default)]
 #[serde(rename_all = "snake_case")]
 #[allow(missing_docs)]
 #[non_exhaustive]
 pub enum BorderColor {
+    #[default]
     Black,
     Borderless,
     Gold,
     White,
     Silver,
-}
-
-impl Default for BorderColor {
-    fn default() -> Self {
-        BorderColor::Black
-    }
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl std::fmt::Display for BorderColor {
@@ -32,6 +30,7 @@ impl std::fmt::Display for BorderColor {
                 Gold => "gold",
                 White => "white",
                 Silver => "silver",
+                Other(name) => name,
             }
         )
     }
@@ -2,30 +2,36 @@
 //!
 //! For documentation about the fields, please refer to the official scryfall
 //! [documentation](https://scryfall.com/docs/api/cards)
-use std::collections::HashMap;
-
 use serde::{Deserialize, Serialize};
 
-use crate::card::Color;
+use crate::card::{Color, ImageUris, Layout, TypeLine};
+use crate::symbology::ManaCost;
 use crate::util::Uuid;
 
 /// Sub card object used when a magic card has more then one card face.
 ///
 /// For documentation about the fields, please refer to the official scryfall
 /// [documentation](https://scryfall.com/docs/api/cards)
-#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 #[allow(missing_docs)]
 pub struct CardFace {
     pub artist: Option<String>,
+    pub artist_id: Option<Uuid>,
+    pub cmc: Option<f32>,
     pub color_indicator: Option<Vec<Color>>,
     #[serde(default)]
     pub colors: Vec<Color>,
+    pub defense: Option<String>,
+    pub flavor_name: Option<String>,
     pub flavor_text: Option<String>,
     pub illustration_id: Option<Uuid>,
-    pub image_uris: Option<HashMap<String, String>>,
+    pub image_uris: Option<ImageUris>,
+    pub layout: Option<Layout>,
     pub loyalty: Option<String>,
+    #[serde(default)]
     pub mana_cost: String,
     pub name: String,
+    pub oracle_id: Option<Uuid>,
     pub oracle_text: Option<String>,
     pub power: Option<String>,
     pub printed_name: Option<String>,
@@ -34,4 +40,20 @@ pub struct CardFace {
     pub toughness: Option<String>,
     pub type_line: Option<String>,
     pub watermark: Option<String>,
+    /// The fields of the face that this crate doesn't model.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl CardFace {
+    /// Parses the face's mana cost.
+    pub fn parse_mana_cost(&self) -> crate::Result<ManaCost> {
+        self.mana_cost.parse()
+    }
+
+    /// Parses the face's type line, or returns `None` if it doesn't have one.
+    pub fn parse_type_line(&self) -> crate::Result<Option<TypeLine>> {
+        self.type_line.as_deref().map(str::parse).transpose()
+    }
 }
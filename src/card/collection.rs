@@ -0,0 +1,252 @@
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+
+use crate::card::Card;
+use crate::util::Uuid;
+
+/// The maximum number of identifiers Scryfall accepts in a single request to
+/// the collection endpoint.
+pub(crate) const MAX_IDENTIFIERS: usize = 75;
+
+/// Identifies a card in a request to
+/// [`Card::collection`][crate::Card::collection].
+///
+/// For documentation on each identifier refer to the
+/// [card collection docs](https://scryfall.com/docs/api/cards/collection) on
+/// the official site.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(try_from = "RawIdentifier", into = "RawIdentifier")]
+pub enum CardIdentifier {
+    /// A card's Scryfall id.
+    Id(Uuid),
+    /// A card's MTGO id.
+    MtgoId(usize),
+    /// A card's multiverse id.
+    MultiverseId(usize),
+    /// A card's Oracle id. The most recent printing is returned.
+    OracleId(Uuid),
+    /// A card's illustration id. The most recent printing is returned.
+    IllustrationId(Uuid),
+    /// A card's exact name. The most recent printing is returned.
+    Name(String),
+    /// A card's exact name, and the code of the set it was printed in.
+    NameAndSet(String, String),
+    /// The code of the set a card was printed in, and its collector number.
+    SetAndNumber(String, String),
+}
+
+/// The cards found by a request to
+/// [`Card::collection`][crate::Card::collection].
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Collection {
+    /// The cards that were found, in the order they were requested.
+    pub cards: Vec<Card>,
+    /// The identifiers that didn't match any card.
+    pub not_found: Vec<CardIdentifier>,
+}
+
+/// The body of a request to the collection endpoint.
+#[derive(Serialize)]
+pub(crate) struct CollectionRequest<'a> {
+    pub(crate) identifiers: &'a [CardIdentifier],
+}
+
+/// The response of the collection endpoint, for a single request.
+#[derive(Deserialize)]
+pub(crate) struct CollectionResponse {
+    data: Vec<Card>,
+    #[serde(default)]
+    not_found: Vec<CardIdentifier>,
+}
+
+impl Collection {
+    /// Adds the cards of a response to the ones found so far.
+    pub(crate) fn extend(&mut self, response: CollectionResponse) {
+        self.cards.extend(response.data);
+        self.not_found.extend(response.not_found);
+    }
+}
+
+/// The JSON representation of a [`CardIdentifier`], an object with only the
+/// fields that identify the card.
+#[derive(Serialize, Deserialize, Default)]
+struct RawIdentifier {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mtgo_id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    multiverse_id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    oracle_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    illustration_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    set: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collector_number: Option<String>,
+}
+
+impl From<CardIdentifier> for RawIdentifier {
+    fn from(identifier: CardIdentifier) -> Self {
+        use CardIdentifier::*;
+        match identifier {
+            Id(id) => RawIdentifier {
+                id: Some(id),
+                ..Default::default()
+            },
+            MtgoId(mtgo_id) => RawIdentifier {
+                mtgo_id: Some(mtgo_id),
+                ..Default::default()
+            },
+            MultiverseId(multiverse_id) => RawIdentifier {
+                multiverse_id: Some(multiverse_id),
+                ..Default::default()
+            },
+            OracleId(oracle_id) => RawIdentifier {
+                oracle_id: Some(oracle_id),
+                ..Default::default()
+            },
+            IllustrationId(illustration_id) => RawIdentifier {
+                illustration_id: Some(illustration_id),
+                ..Default::default()
+            },
+            Name(name) => RawIdentifier {
+                name: Some(name),
+                ..Default::default()
+            },
+            NameAndSet(name, set) => RawIdentifier {
+                name: Some(name),
+                set: Some(set),
+                ..Default::default()
+            },
+            SetAndNumber(set, collector_number) => RawIdentifier {
+                set: Some(set),
+                collector_number: Some(collector_number),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl TryFrom<RawIdentifier> for CardIdentifier {
+    type Error = &'static str;
+
+    fn try_from(raw: RawIdentifier) -> Result<Self, Self::Error> {
+        use CardIdentifier::*;
+        Ok(match raw {
+            RawIdentifier { id: Some(id), .. } => Id(id),
+            RawIdentifier {
+                mtgo_id: Some(mtgo_id),
+                ..
+            } => MtgoId(mtgo_id),
+            RawIdentifier {
+                multiverse_id: Some(multiverse_id),
+                ..
+            } => MultiverseId(multiverse_id),
+            RawIdentifier {
+                oracle_id: Some(oracle_id),
+                ..
+            } => OracleId(oracle_id),
+            RawIdentifier {
+                illustration_id: Some(illustration_id),
+                ..
+            } => IllustrationId(illustration_id),
+            RawIdentifier {
+                name: Some(name),
+                set: Some(set),
+                ..
+            } => NameAndSet(name, set),
+            RawIdentifier {
+                name: Some(name), ..
+            } => Name(name),
+            RawIdentifier {
+                set: Some(set),
+                collector_number: Some(collector_number),
+                ..
+            } => SetAndNumber(set, collector_number),
+            _ => return Err("the object doesn't identify a card"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::{TestResponse, TestServer};
+
+    const LIGHTNING_BOLT: &str = include_str!("../../tests/fixtures/lightning_bolt.json");
+
+    #[test]
+    fn collection_is_requested_in_chunks() {
+        let identifiers = (1..=80)
+            .map(CardIdentifier::MultiverseId)
+            .collect::<Vec<_>>();
+        let server = TestServer::new(vec![
+            TestResponse::json(&format!(
+                r#"{{"object":"list","not_found":[{{"multiverse_id":1}}],"data":[{}]}}"#,
+                LIGHTNING_BOLT
+            )),
+            TestResponse::json(&format!(
+                r#"{{"object":"list","not_found":[],"data":[{}]}}"#,
+                LIGHTNING_BOLT
+            )),
+        ]);
+
+        let collection = Card::collection_with(&server.client(), &identifiers).unwrap();
+        assert_eq!(collection.cards.len(), 2);
+        assert_eq!(collection.cards[0].name, "Lightning Bolt");
+        assert_eq!(collection.not_found, [CardIdentifier::MultiverseId(1)]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        for (request, len) in requests.iter().zip(&[75, 5]) {
+            assert_eq!(request.method, "POST");
+            assert_eq!(request.path, "/cards/collection");
+            let body = serde_json::from_str::<serde_json::Value>(&request.body).unwrap();
+            assert_eq!(body["identifiers"].as_array().unwrap().len(), *len);
+        }
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&requests[1].body).unwrap()["identifiers"][0],
+            serde_json::json!({ "multiverse_id": 76 })
+        );
+    }
+
+    #[test]
+    fn identifiers_round_trip() {
+        let identifiers = vec![
+            (
+                CardIdentifier::Id("683a5707-cddb-494d-9b41-51b4584ded69".parse().unwrap()),
+                r#"{"id":"683a5707-cddb-494d-9b41-51b4584ded69"}"#,
+            ),
+            (CardIdentifier::MtgoId(54957), r#"{"mtgo_id":54957}"#),
+            (
+                CardIdentifier::MultiverseId(409574),
+                r#"{"multiverse_id":409574}"#,
+            ),
+            (
+                CardIdentifier::Name("Ancient Tomb".to_string()),
+                r#"{"name":"Ancient Tomb"}"#,
+            ),
+            (
+                CardIdentifier::NameAndSet("Lightning Bolt".to_string(), "m10".to_string()),
+                r#"{"name":"Lightning Bolt","set":"m10"}"#,
+            ),
+            (
+                CardIdentifier::SetAndNumber("mrd".to_string(), "150".to_string()),
+                r#"{"set":"mrd","collector_number":"150"}"#,
+            ),
+        ];
+        for (identifier, json) in identifiers {
+            assert_eq!(serde_json::to_string(&identifier).unwrap(), json);
+            assert_eq!(
+                serde_json::from_str::<CardIdentifier>(json).unwrap(),
+                identifier
+            );
+        }
+        assert!(serde_json::from_str::<CardIdentifier>(r#"{"set":"mrd"}"#).is_err());
+    }
+}
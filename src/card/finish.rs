@@ -0,0 +1,32 @@
+//! Enum defining the finishes a card can be printed with.
+use serde::{Deserialize, Serialize};
+
+/// Enum defining the finishes a card can be printed with.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub enum Finish {
+    Nonfoil,
+    Foil,
+    Etched,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl std::fmt::Display for Finish {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use Finish::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Nonfoil => "nonfoil",
+                Foil => "foil",
+                Etched => "etched",
+                Other(name) => name,
+            }
+        )
+    }
+}
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 /// Enum describing the various magic card frames
 ///
 /// [Official docs](https://scryfall.com/docs/api/layouts)
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[allow(missing_docs)]
 pub enum Frame {
     #[serde(rename = "1993")]
@@ -17,6 +17,9 @@ pub enum Frame {
     Y2015,
     #[serde(rename = "future")]
     Future,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl std::fmt::Display for Frame {
@@ -31,6 +34,7 @@ impl std::fmt::Display for Frame {
                 Y2003 => "2003",
                 Y2015 => "2015",
                 Future => "future",
+                Other(name) => name,
             }
         )
     }
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 /// Enum describing the various frame effects a border can have.
 ///
 /// [Official docs](https://scryfall.com/docs/api/layouts)
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "lowercase")]
 #[allow(missing_docs)]
 #[non_exhaustive]
@@ -29,6 +29,9 @@ pub enum FrameEffect {
     Nyxborn,
     FullArt,
     Etched,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl std::fmt::Display for FrameEffect {
@@ -58,6 +61,7 @@ impl std::fmt::Display for FrameEffect {
                 Nyxborn => "nyxborn",
                 FullArt => "fullart",
                 Etched => "etched",
+                Other(name) => name,
             }
         )
     }
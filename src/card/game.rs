@@ -2,7 +2,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Enum defining the exiting platforms on with a magic card can exist.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "snake_case")]
 #[allow(missing_docs)]
 #[non_exhaustive]
@@ -12,6 +12,9 @@ pub enum Game {
     Mtgo,
     Astral,
     Sega,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl std::fmt::Display for Game {
@@ -26,6 +29,7 @@ impl std::fmt::Display for Game {
                 Mtgo => "mtgo",
                 Astral => "astral",
                 Sega => "sega",
+                Other(name) => name,
             }
         )
     }
@@ -0,0 +1,436 @@
+//! A directory that keeps the images of cards, so that each of them is only
+//! downloaded once.
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::{Deserialize, Serialize};
+
+use crate::card::{Card, ImageFace, ImageSize};
+use crate::client::ScryfallClient;
+use crate::util::Uuid;
+
+/// The name of the file that records what the cache holds.
+const INDEX_FILE: &str = "index.json";
+
+/// A cache of card images stored in a directory.
+///
+/// Each image is keyed by the card's id, the face and the [`ImageSize`]. An
+/// image is downloaded again if the card now has a high-resolution image and
+/// the stored one wasn't, since Scryfall replaces low-resolution scans as
+/// better ones become available.
+///
+/// The cache can be given a maximum size, in which case the least recently
+/// used images are removed when it grows past it.
+///
+/// All downloads go through the cache's client, and so respect its rate
+/// limit. Images are written to a temporary file first and then moved into
+/// place, so an image is never seen half written. Using a stored image isn't
+/// saved to disk right away: the order of use is recorded the next time an
+/// image is downloaded, or when the cache is dropped.
+///
+/// The cache can be shared between threads, but a directory should only be
+/// used by one `ImageCache` at a time. Each cache keeps its own index of the
+/// directory, and saving it replaces what another cache saved, including the
+/// images that cache downloaded. Eviction may also remove images another
+/// cache is using.
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::card::{Card, ImageCache, ImageFace, ImageSize};
+///
+/// let cache = ImageCache::new("/tmp/scryfall-images")
+///     .unwrap()
+///     .with_max_size(100 * 1024 * 1024);
+/// let card = Card::named("Lightning Bolt").unwrap();
+/// // Only downloads the image the first time.
+/// let path = cache.get(&card, ImageFace::Front, ImageSize::Normal).unwrap();
+/// let image = cache.load(&card, ImageFace::Front, ImageSize::Normal).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ImageCache {
+    dir: PathBuf,
+    client: ScryfallClient,
+    max_size: Option<u64>,
+    index: Mutex<Index>,
+    /// Used to give temporary files unique names.
+    counter: AtomicU64,
+}
+
+/// What the cache holds, saved next to the images.
+#[derive(Serialize, Deserialize, Default, Debug)]
+struct Index {
+    /// Incremented each time an image is used, to order them by last use.
+    clock: u64,
+    entries: HashMap<String, Entry>,
+    /// Whether the index changed since it was last saved.
+    #[serde(skip)]
+    dirty: bool,
+}
+
+/// A stored image.
+#[derive(Serialize, Deserialize, Debug)]
+struct Entry {
+    size: u64,
+    last_used: u64,
+    highres: bool,
+}
+
+impl ImageCache {
+    /// Creates a cache that stores its images in `dir`, and downloads them
+    /// using the default client. The directory is created when the first image
+    /// is downloaded.
+    pub fn new(dir: impl Into<PathBuf>) -> crate::Result<Self> {
+        ImageCache::new_with(ScryfallClient::global(), dir)
+    }
+
+    /// Same as [`new`](#method.new), but the requests are made with the given
+    /// client.
+    pub fn new_with(client: &ScryfallClient, dir: impl Into<PathBuf>) -> crate::Result<Self> {
+        let dir = dir.into();
+        let index = match File::open(dir.join(INDEX_FILE)) {
+            Ok(file) => serde_json::from_reader(BufReader::new(file))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Index::default(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(ImageCache {
+            dir,
+            client: client.clone(),
+            max_size: None,
+            index: Mutex::new(index),
+            counter: AtomicU64::new(0),
+        })
+    }
+
+    /// Limits the total size of the stored images to `max_size` bytes. The
+    /// limit is enforced the next time an image is downloaded. The image
+    /// that was just downloaded is always kept, even if it's larger than the
+    /// limit on its own.
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// The directory the images are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The total size of the stored images, in bytes.
+    pub fn size(&self) -> u64 {
+        self.lock().entries.values().map(|entry| entry.size).sum()
+    }
+
+    /// Returns the path of an image of `card`, downloading it first if it
+    /// isn't stored, or if a high-resolution version became available.
+    pub fn get(&self, card: &Card, face: ImageFace, size: ImageSize) -> crate::Result<PathBuf> {
+        let name = file_name(card.id, face, size);
+        let path = self.dir.join(&name);
+        {
+            let mut index = self.lock();
+            if index.is_fresh(&name, card) && path.is_file() {
+                index.touch(&name);
+                return Ok(path);
+            }
+        }
+
+        fs::create_dir_all(&self.dir)?;
+        let partial = self.temp_path(&name);
+        let written = write_then_rename(&partial, &path, |writer| {
+            card.download_image_to_with(&self.client, face, size, writer)
+        })?;
+
+        let mut index = self.lock();
+        index.entries.insert(
+            name.clone(),
+            Entry {
+                size: written,
+                last_used: 0,
+                highres: card.highres_image,
+            },
+        );
+        index.touch(&name);
+        self.evict(&mut index, &name)?;
+        self.save(&mut index)?;
+        Ok(path)
+    }
+
+    /// Same as [`get`](#method.get), but reads the image.
+    pub fn load(&self, card: &Card, face: ImageFace, size: ImageSize) -> crate::Result<Vec<u8>> {
+        Ok(fs::read(self.get(card, face, size)?)?)
+    }
+
+    /// Downloads the images of the given size of every face of `cards` that
+    /// isn't stored yet. Returns how many images were downloaded.
+    ///
+    /// The downloads are made one after the other, waiting for the client's
+    /// rate limiter.
+    pub fn prefetch<'a>(
+        &self,
+        cards: impl IntoIterator<Item = &'a Card>,
+        size: ImageSize,
+    ) -> crate::Result<usize> {
+        let mut downloaded = 0;
+        for card in cards {
+            for &face in &[ImageFace::Front, ImageFace::Back] {
+                if card.face_image_uris(face).is_none() {
+                    continue;
+                }
+                let name = file_name(card.id, face, size);
+                let fresh = self.lock().is_fresh(&name, card) && self.dir.join(&name).is_file();
+                if !fresh {
+                    self.get(card, face, size)?;
+                    downloaded += 1;
+                }
+            }
+        }
+        Ok(downloaded)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Index> {
+        self.index.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Removes the least recently used images until the cache fits in its
+    /// maximum size, keeping the image named `keep`.
+    fn evict(&self, index: &mut Index, keep: &str) -> crate::Result<()> {
+        let max_size = match self.max_size {
+            Some(max_size) => max_size,
+            None => return Ok(()),
+        };
+        let mut total = index.entries.values().map(|entry| entry.size).sum::<u64>();
+        while total > max_size {
+            let oldest = index
+                .entries
+                .iter()
+                .filter(|(name, _)| name.as_str() != keep)
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(name, _)| name.clone());
+            let oldest = match oldest {
+                Some(oldest) => oldest,
+                None => break,
+            };
+            match fs::remove_file(self.dir.join(&oldest)) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {},
+            }
+            if let Some(entry) = index.entries.remove(&oldest) {
+                total -= entry.size;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the index to a temporary file and moves it over the previous
+    /// one, so that a reader never sees it half written.
+    fn save(&self, index: &mut Index) -> crate::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let temp = self.temp_path(INDEX_FILE);
+        write_then_rename(&temp, &self.dir.join(INDEX_FILE), |writer| {
+            Ok(serde_json::to_writer(writer, &*index)?)
+        })?;
+        index.dirty = false;
+        Ok(())
+    }
+
+    /// A path in the cache's directory that no other thread is writing to, to
+    /// write `name` to before moving it into place.
+    fn temp_path(&self, name: &str) -> PathBuf {
+        let n = self.counter.fetch_add(1, Ordering::Relaxed);
+        self.dir
+            .join(format!("{}.{}-{}.part", name, std::process::id(), n))
+    }
+}
+
+impl Drop for ImageCache {
+    fn drop(&mut self) {
+        let mut index = std::mem::take(self.index.get_mut().unwrap_or_else(|e| e.into_inner()));
+        if index.dirty {
+            // Only the order in which the images were used is lost if this
+            // fails.
+            let _ = self.save(&mut index);
+        }
+    }
+}
+
+impl Index {
+    /// Checks whether the image named `name` is stored, and is as good as the
+    /// one `card` currently has.
+    fn is_fresh(&self, name: &str, card: &Card) -> bool {
+        match self.entries.get(name) {
+            Some(entry) => entry.highres || !card.highres_image,
+            None => false,
+        }
+    }
+
+    /// Marks the image named `name` as the most recently used.
+    fn touch(&mut self, name: &str) {
+        self.clock += 1;
+        self.dirty = true;
+        if let Some(entry) = self.entries.get_mut(name) {
+            entry.last_used = self.clock;
+        }
+    }
+}
+
+/// Writes to `temp` with `write`, then moves it to `path`. The temporary file
+/// is removed if anything fails.
+fn write_then_rename<T>(
+    temp: &Path,
+    path: &Path,
+    write: impl FnOnce(&mut BufWriter<File>) -> crate::Result<T>,
+) -> crate::Result<T> {
+    let result = (|| {
+        let mut writer = BufWriter::new(File::create(temp)?);
+        let value = write(&mut writer)?;
+        writer.flush()?;
+        drop(writer);
+        fs::rename(temp, path)?;
+        Ok(value)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(temp);
+    }
+    result
+}
+
+fn file_name(id: Uuid, face: ImageFace, size: ImageSize) -> String {
+    let extension = match size {
+        ImageSize::Png => "png",
+        _ => "jpg",
+    };
+    format!("{}-{}-{}.{}", id, face, size, extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::{TestResponse, TestServer};
+
+    const LIGHTNING_BOLT: &str = include_str!("../../tests/fixtures/lightning_bolt.json");
+
+    fn card(server: &TestServer, n: u8) -> Card {
+        let mut card = serde_json::from_str::<Card>(LIGHTNING_BOLT).unwrap();
+        card.id = format!("00000000-0000-0000-0000-00000000000{}", n)
+            .parse()
+            .unwrap();
+        card.highres_image = false;
+        let mut image_uris = card.image_uris.take().unwrap();
+        image_uris.normal = server.url().join(&format!("{}.jpg", n)).unwrap();
+        card.image_uris = Some(image_uris);
+        card
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("scryfall-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn evicts_least_recently_used_images() {
+        let server = TestServer::new(vec![
+            TestResponse::new(200, "1111"),
+            TestResponse::new(200, "2222"),
+            TestResponse::new(200, "3333"),
+            TestResponse::new(200, "1111 highres"),
+            TestResponse::new(200, "2222"),
+            TestResponse::new(200, "3333"),
+        ]);
+        let (mut first, second, third) = (card(&server, 1), card(&server, 2), card(&server, 3));
+        let dir = temp_dir("image-cache");
+        let cache = ImageCache::new_with(&server.client(), &dir)
+            .unwrap()
+            .with_max_size(8);
+        let normal = |card| cache.get(card, ImageFace::Front, ImageSize::Normal);
+
+        let path = normal(&first).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"1111");
+        assert_eq!(normal(&first).unwrap(), path);
+        normal(&second).unwrap();
+        normal(&first).unwrap();
+        assert_eq!(cache.size(), 8);
+
+        let evicted = normal(&third).unwrap();
+        assert_eq!(cache.size(), 8);
+        assert!(path.is_file() && evicted.is_file());
+        assert!(!dir
+            .join(file_name(second.id, ImageFace::Front, ImageSize::Normal))
+            .exists());
+
+        first.highres_image = true;
+        let image = cache
+            .load(&first, ImageFace::Front, ImageSize::Normal)
+            .unwrap();
+        assert_eq!(image, b"1111 highres");
+        assert_eq!(cache.size(), 12);
+        assert!(!evicted.exists());
+
+        let reopened = ImageCache::new_with(&server.client(), &dir).unwrap();
+        assert_eq!(reopened.size(), 12);
+        let cards = [first.clone(), second.clone(), third.clone()];
+        assert_eq!(reopened.prefetch(&cards, ImageSize::Normal).unwrap(), 2);
+
+        let paths = server
+            .requests()
+            .into_iter()
+            .map(|r| r.path)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            ["/1.jpg", "/2.jpg", "/3.jpg", "/1.jpg", "/2.jpg", "/3.jpg"]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn saves_uses_when_dropped() {
+        let server = TestServer::new(vec![
+            TestResponse::new(200, "1111"),
+            TestResponse::new(200, "2222"),
+        ]);
+        let (first, second) = (card(&server, 1), card(&server, 2));
+        let dir = temp_dir("image-cache-drop");
+        let index_path = dir.join(INDEX_FILE);
+        let cache = ImageCache::new_with(&server.client(), &dir).unwrap();
+        cache
+            .get(&first, ImageFace::Front, ImageSize::Normal)
+            .unwrap();
+        cache
+            .get(&second, ImageFace::Front, ImageSize::Normal)
+            .unwrap();
+        let saved = fs::read(&index_path).unwrap();
+        cache
+            .get(&first, ImageFace::Front, ImageSize::Normal)
+            .unwrap();
+        assert_eq!(fs::read(&index_path).unwrap(), saved);
+
+        drop(cache);
+        let reopened = ImageCache::new_with(&server.client(), &dir)
+            .unwrap()
+            .with_max_size(4);
+        {
+            let mut index = reopened.lock();
+            reopened.evict(&mut index, "").unwrap();
+        }
+        let mut files = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(
+            files,
+            [
+                file_name(first.id, ImageFace::Front, ImageSize::Normal),
+                INDEX_FILE.to_string(),
+            ]
+        );
+
+        drop(reopened);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,140 @@
+//! This module defines the links to the images of a card, in each of the sizes
+//! Scryfall provides.
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// The links to the images of a card, or of one of its faces.
+///
+/// For documentation on each size refer to the
+/// [card imagery docs](https://scryfall.com/docs/api/images) on the official
+/// site.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[allow(missing_docs)]
+pub struct ImageUris {
+    pub small: Url,
+    pub normal: Url,
+    pub large: Url,
+    pub png: Url,
+    pub art_crop: Url,
+    pub border_crop: Url,
+}
+
+/// The sizes and crops of card images Scryfall provides.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageSize {
+    /// A small JPG of the full card, 146 × 204.
+    Small,
+    /// A medium-sized JPG of the full card, 488 × 680.
+    Normal,
+    /// A large JPG of the full card, 672 × 936.
+    Large,
+    /// A transparent, rounded PNG of the full card, 745 × 1040.
+    Png,
+    /// A JPG of the card's art only. Its size varies.
+    ArtCrop,
+    /// A JPG of the full card without its border, 480 × 680.
+    BorderCrop,
+}
+
+/// The face of a card whose image is requested.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageFace {
+    /// The front face, or the only face of a card with a single image.
+    Front,
+    /// The back face of a double-faced card.
+    Back,
+}
+
+/// How good the images Scryfall has of a card are.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ImageStatus {
+    /// The card has no image, or it isn't available yet.
+    Missing,
+    /// The card has a placeholder image, such as one made from a preview.
+    Placeholder,
+    /// The card's image is a low-resolution scan or photo.
+    Lowres,
+    /// The card has a high-resolution scan.
+    HighresScan,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl ImageUris {
+    /// The link to the image of the given size.
+    pub fn get(&self, size: ImageSize) -> &Url {
+        match size {
+            ImageSize::Small => &self.small,
+            ImageSize::Normal => &self.normal,
+            ImageSize::Large => &self.large,
+            ImageSize::Png => &self.png,
+            ImageSize::ArtCrop => &self.art_crop,
+            ImageSize::BorderCrop => &self.border_crop,
+        }
+    }
+}
+
+impl ImageFace {
+    /// The index of this face in [`Card::card_faces`][crate::Card::card_faces].
+    pub(crate) fn index(self) -> usize {
+        match self {
+            ImageFace::Front => 0,
+            ImageFace::Back => 1,
+        }
+    }
+}
+
+impl fmt::Display for ImageSize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ImageSize::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Small => "small",
+                Normal => "normal",
+                Large => "large",
+                Png => "png",
+                ArtCrop => "art_crop",
+                BorderCrop => "border_crop",
+            }
+        )
+    }
+}
+
+impl fmt::Display for ImageFace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ImageFace::Front => "front",
+                ImageFace::Back => "back",
+            }
+        )
+    }
+}
+
+impl fmt::Display for ImageStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ImageStatus::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Missing => "missing",
+                Placeholder => "placeholder",
+                Lowres => "lowres",
+                HighresScan => "highres_scan",
+                Other(name) => name,
+            }
+        )
+    }
+}
@@ -0,0 +1,99 @@
+//! Enum defining the languages Scryfall has printings of cards in.
+use serde::{Deserialize, Serialize};
+
+/// Enum defining the languages Scryfall has printings of cards in.
+///
+/// [Official docs](https://scryfall.com/docs/api/languages)
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[non_exhaustive]
+pub enum Language {
+    /// English, `en`.
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    /// Spanish, `es`.
+    #[serde(rename = "es")]
+    Spanish,
+    /// French, `fr`.
+    #[serde(rename = "fr")]
+    French,
+    /// German, `de`.
+    #[serde(rename = "de")]
+    German,
+    /// Italian, `it`.
+    #[serde(rename = "it")]
+    Italian,
+    /// Portuguese, `pt`.
+    #[serde(rename = "pt")]
+    Portuguese,
+    /// Japanese, `ja`.
+    #[serde(rename = "ja")]
+    Japanese,
+    /// Korean, `ko`.
+    #[serde(rename = "ko")]
+    Korean,
+    /// Russian, `ru`.
+    #[serde(rename = "ru")]
+    Russian,
+    /// Simplified Chinese, `zhs`.
+    #[serde(rename = "zhs")]
+    SimplifiedChinese,
+    /// Traditional Chinese, `zht`.
+    #[serde(rename = "zht")]
+    TraditionalChinese,
+    /// Hebrew, `he`.
+    #[serde(rename = "he")]
+    Hebrew,
+    /// Latin, `la`.
+    #[serde(rename = "la")]
+    Latin,
+    /// Ancient Greek, `grc`.
+    #[serde(rename = "grc")]
+    AncientGreek,
+    /// Arabic, `ar`.
+    #[serde(rename = "ar")]
+    Arabic,
+    /// Sanskrit, `sa`.
+    #[serde(rename = "sa")]
+    Sanskrit,
+    /// Phyrexian, `ph`.
+    #[serde(rename = "ph")]
+    Phyrexian,
+    /// Quenya, `qya`.
+    #[serde(rename = "qya")]
+    Quenya,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use Language::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                English => "en",
+                Spanish => "es",
+                French => "fr",
+                German => "de",
+                Italian => "it",
+                Portuguese => "pt",
+                Japanese => "ja",
+                Korean => "ko",
+                Russian => "ru",
+                SimplifiedChinese => "zhs",
+                TraditionalChinese => "zht",
+                Hebrew => "he",
+                Latin => "la",
+                AncientGreek => "grc",
+                Arabic => "ar",
+                Sanskrit => "sa",
+                Phyrexian => "ph",
+                Quenya => "qya",
+                Other(code) => code,
+            }
+        )
+    }
+}
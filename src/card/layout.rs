@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 /// Enum describing the various layouts a magic card can have.
 ///
 /// [Official docs](https://scryfall.com/docs/api/layouts)
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "snake_case")]
 #[allow(missing_docs)]
 #[non_exhaustive]
@@ -28,4 +28,51 @@ pub enum Layout {
     ArtSeries,
     DoubleSided,
     ModalDfc,
+    Class,
+    Case,
+    Mutate,
+    Prototype,
+    Battle,
+    ReversibleCard,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl std::fmt::Display for Layout {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use Layout::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Normal => "normal",
+                Split => "split",
+                Flip => "flip",
+                Transform => "transform",
+                Meld => "meld",
+                Leveler => "leveler",
+                Saga => "saga",
+                Adventure => "adventure",
+                Planar => "planar",
+                Scheme => "scheme",
+                Vanguard => "vanguard",
+                Token => "token",
+                DoubleFacedToken => "double_faced_token",
+                Emblem => "emblem",
+                Augment => "augment",
+                Host => "host",
+                ArtSeries => "art_series",
+                DoubleSided => "double_sided",
+                ModalDfc => "modal_dfc",
+                Class => "class",
+                Case => "case",
+                Mutate => "mutate",
+                Prototype => "prototype",
+                Battle => "battle",
+                ReversibleCard => "reversible_card",
+                Other(name) => name,
+            }
+        )
+    }
 }
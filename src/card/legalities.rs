@@ -0,0 +1,140 @@
+//! Module defining the legalities of a card in every format.
+use std::borrow::Borrow;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::card::Legality;
+use crate::format::Format;
+
+/// The legality of a card in every format.
+///
+/// Formats this crate doesn't know about are kept apart, by the name Scryfall
+/// uses, so that a new format doesn't break deserialization. They can still
+/// be looked up with [`Format::Other`].
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::{Card, Legality};
+/// use scryfall::format::Format;
+///
+/// let card = Card::named("Lightning Bolt").unwrap();
+/// assert_eq!(card.legalities.get(Format::Modern), Some(Legality::Legal));
+/// assert!(card.legalities.legal_in().any(|format| format == Format::Legacy));
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct Legalities {
+    formats: HashMap<Format, Legality>,
+    unknown: HashMap<String, Legality>,
+}
+
+impl Legalities {
+    /// The legality of the card in `format`, if Scryfall reported one.
+    pub fn get(&self, format: impl Borrow<Format>) -> Option<Legality> {
+        match format.borrow() {
+            Format::Other(name) => self.unknown.get(name).copied(),
+            format => self.formats.get(format).copied(),
+        }
+    }
+
+    /// Checks if the card is legal in `format`.
+    pub fn is_legal(&self, format: impl Borrow<Format>) -> bool {
+        self.get(format) == Some(Legality::Legal)
+    }
+
+    /// Sets the legality of the card in `format`.
+    pub fn insert(&mut self, format: Format, legality: Legality) {
+        match format {
+            Format::Other(name) => self.unknown.insert(name, legality),
+            format => self.formats.insert(format, legality),
+        };
+    }
+
+    /// Iterates over every format and the card's legality in it, including
+    /// unknown formats.
+    pub fn iter(&self) -> impl Iterator<Item = (Format, Legality)> + '_ {
+        let formats = self
+            .formats
+            .iter()
+            .map(|(format, legality)| (format.clone(), *legality));
+        let unknown = self
+            .unknown
+            .iter()
+            .map(|(name, legality)| (Format::Other(name.clone()), *legality));
+        formats.chain(unknown)
+    }
+
+    /// The formats, by name, that this crate doesn't know about.
+    pub fn unknown(&self) -> &HashMap<String, Legality> {
+        &self.unknown
+    }
+
+    /// The formats the card is legal in.
+    pub fn legal_in(&self) -> impl Iterator<Item = Format> + '_ {
+        self.with_legality(Legality::Legal)
+    }
+
+    /// The formats the card is banned in.
+    pub fn banned_in(&self) -> impl Iterator<Item = Format> + '_ {
+        self.with_legality(Legality::Banned)
+    }
+
+    /// The formats the card is restricted in.
+    pub fn restricted_in(&self) -> impl Iterator<Item = Format> + '_ {
+        self.with_legality(Legality::Restricted)
+    }
+
+    fn with_legality(&self, legality: Legality) -> impl Iterator<Item = Format> + '_ {
+        self.iter()
+            .filter(move |(_, l)| *l == legality)
+            .map(|(format, _)| format)
+    }
+}
+
+impl Serialize for Legalities {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.iter()
+                .map(|(format, legality)| (format.to_string(), legality)),
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for Legalities {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut legalities = Legalities::default();
+        for (format, legality) in HashMap::<Format, Legality>::deserialize(deserializer)? {
+            legalities.insert(format, legality);
+        }
+        Ok(legalities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_formats_are_kept() {
+        let json = r#"{"modern":"legal","vintage":"restricted","newformat":"banned","pioneer":"not_legal"}"#;
+        let legalities = serde_json::from_str::<Legalities>(json).unwrap();
+        assert_eq!(legalities.get(Format::Modern), Some(Legality::Legal));
+        assert_eq!(legalities.get(&Format::Legacy), None);
+        assert!(!legalities.is_legal(Format::Pioneer));
+        assert_eq!(legalities.unknown()["newformat"], Legality::Banned);
+        assert_eq!(
+            legalities.banned_in().collect::<Vec<_>>(),
+            [Format::Other("newformat".to_string())]
+        );
+        assert_eq!(
+            legalities.restricted_in().collect::<Vec<_>>(),
+            [Format::Vintage]
+        );
+
+        let round_trip = serde_json::to_string(&legalities).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Legalities>(&round_trip).unwrap(),
+            legalities
+        );
+    }
+}
@@ -1,25 +1,86 @@
 //! Module defining a price object containing data in various currencies.
 use std::cmp::Ordering;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul};
 
+pub use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+use crate::card::Finish;
+
 /// Struct defining a price object containing data in various currencies.
+///
+/// Prices are exact decimals, written as strings like Scryfall does when
+/// serialized. They can be added together, so the value of a deck is the sum
+/// of the prices of its cards. The sum has no price in a currency and finish
+/// that any of the cards lacks, rather than a total that's too low.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::{Currency, Decimal, Finish, Price};
+///
+/// let bolt = Price {
+///     usd: Some("1.10".parse().unwrap()),
+///     usd_foil: Some("4.99".parse().unwrap()),
+///     ..Default::default()
+/// };
+/// let island = Price {
+///     usd: Some("0.20".parse().unwrap()),
+///     ..Default::default()
+/// };
+/// let deck = bolt * 4 + island * 20;
+/// assert_eq!(
+///     deck.get(Currency::Usd, &Finish::Nonfoil),
+///     Some(Decimal::new(840, 2))
+/// );
+/// // Island has no foil price, so neither has the deck.
+/// assert_eq!(deck.get(Currency::Usd, &Finish::Foil), None);
+/// ```
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug, Default)]
 #[allow(missing_docs)]
 pub struct Price {
-    pub usd: Option<String>,
-    pub usd_foil: Option<String>,
-    pub eur: Option<String>,
-    pub eur_foil: Option<String>,
-    pub tix: Option<String>,
+    pub usd: Option<Decimal>,
+    pub usd_foil: Option<Decimal>,
+    #[serde(default)]
+    pub usd_etched: Option<Decimal>,
+    pub eur: Option<Decimal>,
+    pub eur_foil: Option<Decimal>,
+    pub tix: Option<Decimal>,
+}
+
+/// The currencies Scryfall provides prices in.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Currency {
+    /// US dollars, from TCGplayer.
+    Usd,
+    /// Euros, from Cardmarket.
+    Eur,
+    /// MTGO event tickets, from Cardhoarder.
+    Tix,
 }
 
 impl Price {
+    /// The price in `currency` of the given finish, if Scryfall has one. Tix
+    /// prices only exist for nonfoil cards, and etched prices only in dollars.
+    pub fn get(&self, currency: Currency, finish: &Finish) -> Option<Decimal> {
+        match (currency, finish) {
+            (Currency::Usd, Finish::Nonfoil) => self.usd,
+            (Currency::Usd, Finish::Foil) => self.usd_foil,
+            (Currency::Usd, Finish::Etched) => self.usd_etched,
+            (Currency::Eur, Finish::Nonfoil) => self.eur,
+            (Currency::Eur, Finish::Foil) => self.eur_foil,
+            (Currency::Tix, Finish::Nonfoil) => self.tix,
+            _ => None,
+        }
+    }
+
     /// Creates an array of component prices that can be iterated over.
-    fn to_array(&self) -> [&Option<String>; 5] {
+    fn to_array(&self) -> [&Option<Decimal>; 6] {
         [
             &self.usd,
             &self.usd_foil,
+            &self.usd_etched,
             &self.eur,
             &self.eur_foil,
             &self.tix,
@@ -27,21 +88,19 @@ impl Price {
     }
 }
 
-/// Compares two prices as floating-point numbers.
-fn compare_prices(a: &Option<String>, b: &Option<String>) -> Option<Ordering> {
-    if let (Some(a), Some(b)) = (a, b) {
-        if let (Ok(a), Ok(b)) = (a.parse::<f32>(), b.parse()) {
-            return a.partial_cmp(&b);
-        }
+/// Compares two prices.
+fn compare_prices(a: &Option<Decimal>, b: &Option<Decimal>) -> Option<Ordering> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.cmp(b)),
+        _ => None,
     }
-    None
 }
 
 impl PartialOrd for Price {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         let mut result = None;
         for (a, b) in self.to_array().iter().zip(other.to_array().iter()) {
-            match (result, compare_prices(*a, *b)) {
+            match (result, compare_prices(a, b)) {
                 // If either ordering is `None`, use the other. Then if either is `Some(Equal)`,
                 // use the other.
                 (None, order)
@@ -60,10 +119,79 @@ impl PartialOrd for Price {
     }
 }
 
+/// Adds each component price. A price missing on either side is missing from
+/// the result, since the total isn't known.
+impl Add for Price {
+    type Output = Price;
+
+    fn add(self, other: Price) -> Price {
+        fn add(a: Option<Decimal>, b: Option<Decimal>) -> Option<Decimal> {
+            Some(a? + b?)
+        }
+        Price {
+            usd: add(self.usd, other.usd),
+            usd_foil: add(self.usd_foil, other.usd_foil),
+            usd_etched: add(self.usd_etched, other.usd_etched),
+            eur: add(self.eur, other.eur),
+            eur_foil: add(self.eur_foil, other.eur_foil),
+            tix: add(self.tix, other.tix),
+        }
+    }
+}
+
+impl AddAssign for Price {
+    fn add_assign(&mut self, other: Price) {
+        *self = std::mem::take(self) + other;
+    }
+}
+
+/// Multiplies each component price by a number of copies.
+impl Mul<u32> for Price {
+    type Output = Price;
+
+    fn mul(self, copies: u32) -> Price {
+        let copies = Decimal::from(copies);
+        Price {
+            usd: self.usd.map(|price| price * copies),
+            usd_foil: self.usd_foil.map(|price| price * copies),
+            usd_etched: self.usd_etched.map(|price| price * copies),
+            eur: self.eur.map(|price| price * copies),
+            eur_foil: self.eur_foil.map(|price| price * copies),
+            tix: self.tix.map(|price| price * copies),
+        }
+    }
+}
+
+/// Sums each component price. The sum of no prices is zero in every currency.
+impl Sum for Price {
+    fn sum<I: Iterator<Item = Price>>(iter: I) -> Price {
+        let zero = Some(Decimal::ZERO);
+        let zero = Price {
+            usd: zero,
+            usd_foil: zero,
+            usd_etched: zero,
+            eur: zero,
+            eur_foil: zero,
+            tix: zero,
+        };
+        iter.fold(zero, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Price> for Price {
+    fn sum<I: Iterator<Item = &'a Price>>(iter: I) -> Price {
+        iter.cloned().sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn price(s: &str) -> Option<Decimal> {
+        Some(s.parse().unwrap())
+    }
+
     #[test]
     fn no_prices() {
         let a = Price::default();
@@ -75,15 +203,15 @@ mod tests {
     #[test]
     fn prices_agree() {
         let a = Price {
-            usd: Some("5".to_string()),
-            usd_foil: Some("8".to_string()),
-            eur: Some("3".to_string()),
+            usd: price("5"),
+            usd_foil: price("8"),
+            eur: price("3"),
             ..Default::default()
         };
         let b = Price {
-            usd: Some("10".to_string()),
-            usd_foil: Some("14".to_string()),
-            tix: Some("1".to_string()),
+            usd: price("10"),
+            usd_foil: price("14"),
+            tix: price("1"),
             ..Default::default()
         };
 
@@ -93,13 +221,13 @@ mod tests {
     #[test]
     fn prices_disagree() {
         let a = Price {
-            usd: Some("0.1".to_string()),
-            tix: Some("15".to_string()),
+            usd: price("0.1"),
+            tix: price("15"),
             ..Default::default()
         };
         let b = Price {
-            usd: Some("2".to_string()),
-            tix: Some(".5".to_string()),
+            usd: price("2"),
+            tix: price(".5"),
             ..Default::default()
         };
 
@@ -109,16 +237,68 @@ mod tests {
     #[test]
     fn prices_equal() {
         let a = Price {
-            usd: Some("3.99".to_string()),
-            tix: Some("2.1".to_string()),
+            usd: price("3.99"),
+            tix: price("2.1"),
             ..Default::default()
         };
         let b = Price {
-            usd: Some("3.99".to_string()),
-            eur: Some("4.20".to_string()),
+            usd: price("3.99"),
+            eur: price("4.20"),
             ..Default::default()
         };
 
         assert_eq!(a.partial_cmp(&b), Some(Ordering::Equal));
     }
+
+    #[test]
+    fn prices_round_trip() {
+        let json = r#"{"usd":"0.10","usd_foil":null,"usd_etched":"12.34","eur":"0.07","eur_foil":null,"tix":"0.01"}"#;
+        let prices = serde_json::from_str::<Price>(json).unwrap();
+        assert_eq!(prices.get(Currency::Usd, &Finish::Nonfoil), price("0.1"));
+        assert_eq!(prices.get(Currency::Usd, &Finish::Etched), price("12.34"));
+        assert_eq!(prices.get(Currency::Usd, &Finish::Foil), None);
+        assert_eq!(prices.get(Currency::Tix, &Finish::Foil), None);
+        assert_eq!(serde_json::to_string(&prices).unwrap(), json);
+
+        let old = serde_json::from_str::<Price>(
+            r#"{"usd":"1","usd_foil":null,"eur":null,"eur_foil":null,"tix":null}"#,
+        )
+        .unwrap();
+        assert_eq!(old.usd_etched, None);
+    }
+
+    #[test]
+    fn sum_prices() {
+        let a = Price {
+            usd: price("0.10"),
+            eur: price("0.20"),
+            ..Default::default()
+        };
+        let b = Price {
+            usd: price("0.20"),
+            tix: price("0.05"),
+            ..Default::default()
+        };
+        let total = [a.clone(), b, a.clone() * 2].iter().sum::<Price>();
+        assert_eq!(total.usd, price("0.50"));
+        assert_eq!(total.eur, None);
+        assert_eq!(total.tix, None);
+        assert_eq!(total.usd_foil, None);
+        assert_eq!((a.clone() + a).eur, price("0.40"));
+
+        let empty = std::iter::empty::<Price>().sum::<Price>();
+        assert_eq!(empty.tix, Some(Decimal::ZERO));
+
+        let mut sum = Price {
+            usd: price("0"),
+            ..Default::default()
+        };
+        for _ in 0..10 {
+            sum += Price {
+                usd: price("0.1"),
+                ..Default::default()
+            };
+        }
+        assert_eq!(sum.usd, price("1"));
+    }
 }
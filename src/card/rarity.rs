@@ -1,13 +1,37 @@
-//! Enum defining the 4 different rarities a card can come in.
+//! Enum defining the different rarities a card can come in.
 use serde::{Deserialize, Serialize};
 
-/// Enum defining the 4 different rarities a card can come in.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+/// Enum defining the different rarities a card can come in.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 #[serde(rename_all = "snake_case")]
 #[allow(missing_docs)]
 pub enum Rarity {
     Common,
     Uncommon,
     Rare,
+    Special,
     Mythic,
+    Bonus,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl std::fmt::Display for Rarity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use Rarity::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Common => "common",
+                Uncommon => "uncommon",
+                Rare => "rare",
+                Special => "special",
+                Mythic => "mythic",
+                Bonus => "bonus",
+                Other(name) => name,
+            }
+        )
+    }
 }
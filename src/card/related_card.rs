@@ -19,10 +19,14 @@ pub struct RelatedCard {
     pub name: String,
     pub type_line: String,
     pub uri: Uri<Card>,
+    /// The fields of the related card that this crate doesn't model.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The kind of related card.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "snake_case")]
 #[allow(missing_docs)]
 #[non_exhaustive]
@@ -31,4 +35,24 @@ pub enum Component {
     MeldPart,
     MeldResult,
     ComboPiece,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl std::fmt::Display for Component {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use Component::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Token => "token",
+                MeldPart => "meld_part",
+                MeldResult => "meld_result",
+                ComboPiece => "combo_piece",
+                Other(name) => name,
+            }
+        )
+    }
 }
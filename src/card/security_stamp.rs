@@ -0,0 +1,38 @@
+//! Enum defining the security stamps printed on cards.
+use serde::{Deserialize, Serialize};
+
+/// Enum defining the security stamps printed on cards.
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(rename_all = "snake_case")]
+#[allow(missing_docs)]
+#[non_exhaustive]
+pub enum SecurityStamp {
+    Oval,
+    Triangle,
+    Acorn,
+    Circle,
+    Arena,
+    Heart,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl std::fmt::Display for SecurityStamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use SecurityStamp::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Oval => "oval",
+                Triangle => "triangle",
+                Acorn => "acorn",
+                Circle => "circle",
+                Arena => "arena",
+                Heart => "heart",
+                Other(name) => name,
+            }
+        )
+    }
+}
@@ -0,0 +1,442 @@
+//! This module defines the type line of a card, split into its supertypes,
+//! card types and subtypes.
+use std::fmt;
+use std::str::FromStr;
+
+use crate::catalog::Catalog;
+use crate::client::ScryfallClient;
+use crate::error::Error;
+
+/// The words that are supertypes rather than card types. Scryfall also writes
+/// `Token` before the types of tokens, so it's treated as one.
+const SUPERTYPES: &[&str] = &[
+    "Basic",
+    "Elite",
+    "Host",
+    "Legendary",
+    "Ongoing",
+    "Snow",
+    "Token",
+    "World",
+];
+
+/// Subtypes made of more than one word. Planar types are also multi-word, but
+/// a plane only ever has one, so they don't need to be listed.
+const MULTI_WORD_SUBTYPES: &[&str] = &["Time Lord"];
+
+/// The type line of a card, like `Legendary Creature — Elf Druid`.
+///
+/// The type lines of cards with several faces are joined with `//`, and each
+/// of them is parsed into its own [`TypeLineFace`].
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::TypeLine;
+///
+/// let type_line = "Legendary Creature — Elf Druid".parse::<TypeLine>().unwrap();
+/// assert!(type_line.is_legendary() && type_line.is_creature());
+/// assert!(type_line.has_subtype("Elf"));
+///
+/// let type_line = "Creature — Human Werewolf // Creature — Werewolf"
+///     .parse::<TypeLine>()
+///     .unwrap();
+/// assert_eq!(type_line.faces().len(), 2);
+/// assert_eq!(type_line.faces()[1].subtypes, ["Werewolf"]);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TypeLine {
+    faces: Vec<TypeLineFace>,
+}
+
+/// The types of one face of a card.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TypeLineFace {
+    /// The supertypes, like `Legendary` or `Basic`.
+    pub supertypes: Vec<String>,
+    /// The card types, like `Creature` or `Instant`. There's always at least
+    /// one.
+    pub types: Vec<String>,
+    /// The subtypes, written after the dash, like `Elf` or `Equipment`.
+    pub subtypes: Vec<String>,
+}
+
+/// The catalogs of subtypes a [`TypeLine`] can be validated against. See
+/// [`TypeLine::validate`].
+///
+/// Catalogs that are `None` aren't checked.
+#[derive(Clone, Default, Debug)]
+pub struct SubtypeCatalogs {
+    /// The subtypes of creatures and kindred cards.
+    pub creature_types: Option<Catalog>,
+    /// The subtypes of planeswalkers.
+    pub planeswalker_types: Option<Catalog>,
+    /// The subtypes of lands.
+    pub land_types: Option<Catalog>,
+    /// The subtypes of artifacts.
+    pub artifact_types: Option<Catalog>,
+    /// The subtypes of enchantments.
+    pub enchantment_types: Option<Catalog>,
+    /// The subtypes of instants and sorceries.
+    pub spell_types: Option<Catalog>,
+}
+
+impl TypeLine {
+    /// The faces of this type line. Cards with a single face have one.
+    pub fn faces(&self) -> &[TypeLineFace] {
+        &self.faces
+    }
+
+    /// Checks if any face has the given supertype.
+    pub fn has_supertype(&self, supertype: &str) -> bool {
+        self.faces.iter().any(|face| face.has_supertype(supertype))
+    }
+
+    /// Checks if any face has the given card type.
+    pub fn has_type(&self, card_type: &str) -> bool {
+        self.faces.iter().any(|face| face.has_type(card_type))
+    }
+
+    /// Checks if any face has the given subtype.
+    pub fn has_subtype(&self, subtype: &str) -> bool {
+        self.faces.iter().any(|face| face.has_subtype(subtype))
+    }
+
+    /// Checks if any face is legendary.
+    pub fn is_legendary(&self) -> bool {
+        self.has_supertype("Legendary")
+    }
+
+    /// Checks if any face is a basic card.
+    pub fn is_basic(&self) -> bool {
+        self.has_supertype("Basic")
+    }
+
+    /// Checks if any face is an artifact.
+    pub fn is_artifact(&self) -> bool {
+        self.has_type("Artifact")
+    }
+
+    /// Checks if any face is a creature.
+    pub fn is_creature(&self) -> bool {
+        self.has_type("Creature")
+    }
+
+    /// Checks if any face is an enchantment.
+    pub fn is_enchantment(&self) -> bool {
+        self.has_type("Enchantment")
+    }
+
+    /// Checks if any face is an instant.
+    pub fn is_instant(&self) -> bool {
+        self.has_type("Instant")
+    }
+
+    /// Checks if any face is a land.
+    pub fn is_land(&self) -> bool {
+        self.has_type("Land")
+    }
+
+    /// Checks if any face is a planeswalker.
+    pub fn is_planeswalker(&self) -> bool {
+        self.has_type("Planeswalker")
+    }
+
+    /// Checks if any face is a sorcery.
+    pub fn is_sorcery(&self) -> bool {
+        self.has_type("Sorcery")
+    }
+
+    /// Checks that every subtype belongs to one of the catalogs of its face's
+    /// card types. For example, the subtypes of a creature must be in
+    /// `creature_types`.
+    ///
+    /// A subtype can't be checked if one of its face's card types has no
+    /// catalog, either because it wasn't provided or because Scryfall doesn't
+    /// have one, and is then considered valid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::{SubtypeCatalogs, TypeLine};
+    ///
+    /// let catalogs = SubtypeCatalogs::fetch().unwrap();
+    /// let type_line = "Creature — Elf Druid".parse::<TypeLine>().unwrap();
+    /// assert!(type_line.validate(&catalogs).is_ok());
+    /// let type_line = "Creature — Forest".parse::<TypeLine>().unwrap();
+    /// assert!(type_line.validate(&catalogs).is_err());
+    /// ```
+    pub fn validate(&self, catalogs: &SubtypeCatalogs) -> crate::Result<()> {
+        for face in &self.faces {
+            let catalogs = face
+                .types
+                .iter()
+                .map(|card_type| catalogs.for_type(card_type))
+                .collect::<Option<Vec<_>>>();
+            let catalogs = match catalogs {
+                Some(catalogs) => catalogs,
+                None => continue,
+            };
+            for subtype in &face.subtypes {
+                if !catalogs
+                    .iter()
+                    .any(|catalog| catalog.data.contains(subtype))
+                {
+                    return Err(Error::UnknownSubtype {
+                        subtype: subtype.clone(),
+                        type_line: self.to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TypeLineFace {
+    /// Checks if this face has the given supertype, ignoring case.
+    pub fn has_supertype(&self, supertype: &str) -> bool {
+        contains(&self.supertypes, supertype)
+    }
+
+    /// Checks if this face has the given card type, ignoring case.
+    pub fn has_type(&self, card_type: &str) -> bool {
+        contains(&self.types, card_type)
+    }
+
+    /// Checks if this face has the given subtype, ignoring case.
+    pub fn has_subtype(&self, subtype: &str) -> bool {
+        contains(&self.subtypes, subtype)
+    }
+
+    fn parse(face: &str) -> Option<TypeLineFace> {
+        let (types, subtypes) = match face.split_once('—') {
+            Some((types, subtypes)) => (types, Some(subtypes.trim())),
+            None => (face, None),
+        };
+        let (supertypes, types) = types
+            .split_whitespace()
+            .map(ToString::to_string)
+            .partition::<Vec<_>, _>(|word| SUPERTYPES.contains(&word.as_str()));
+        if types.is_empty() {
+            return None;
+        }
+        let subtypes = match subtypes {
+            None => Vec::new(),
+            Some("") => return None,
+            Some(plane) if contains(&types, "Plane") => vec![plane.to_string()],
+            Some(subtypes) => split_subtypes(subtypes),
+        };
+        Some(TypeLineFace {
+            supertypes,
+            types,
+            subtypes,
+        })
+    }
+}
+
+impl SubtypeCatalogs {
+    /// Fetches all the catalogs of subtypes.
+    pub fn fetch() -> crate::Result<Self> {
+        SubtypeCatalogs::fetch_with(ScryfallClient::global())
+    }
+
+    /// Same as [`fetch`](#method.fetch), but the requests are made with the
+    /// given client.
+    pub fn fetch_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Ok(SubtypeCatalogs {
+            creature_types: Some(Catalog::creature_types_with(client)?),
+            planeswalker_types: Some(Catalog::planeswalker_types_with(client)?),
+            land_types: Some(Catalog::land_types_with(client)?),
+            artifact_types: Some(Catalog::artifact_types_with(client)?),
+            enchantment_types: Some(Catalog::enchantment_types_with(client)?),
+            spell_types: Some(Catalog::spell_types_with(client)?),
+        })
+    }
+
+    /// The catalog of the subtypes of a card type, if it was provided.
+    fn for_type(&self, card_type: &str) -> Option<&Catalog> {
+        match card_type {
+            "Creature" | "Kindred" | "Tribal" => self.creature_types.as_ref(),
+            "Planeswalker" => self.planeswalker_types.as_ref(),
+            "Land" => self.land_types.as_ref(),
+            "Artifact" => self.artifact_types.as_ref(),
+            "Enchantment" => self.enchantment_types.as_ref(),
+            "Instant" | "Sorcery" => self.spell_types.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl SubtypeCatalogs {
+    /// Asynchronous version of [`fetch`](#method.fetch).
+    pub async fn fetch_async() -> crate::Result<Self> {
+        SubtypeCatalogs::fetch_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`fetch_async`](#method.fetch_async), but the requests are made
+    /// with the given client.
+    pub async fn fetch_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Ok(SubtypeCatalogs {
+            creature_types: Some(Catalog::creature_types_async_with(client).await?),
+            planeswalker_types: Some(Catalog::planeswalker_types_async_with(client).await?),
+            land_types: Some(Catalog::land_types_async_with(client).await?),
+            artifact_types: Some(Catalog::artifact_types_async_with(client).await?),
+            enchantment_types: Some(Catalog::enchantment_types_async_with(client).await?),
+            spell_types: Some(Catalog::spell_types_async_with(client).await?),
+        })
+    }
+}
+
+impl FromStr for TypeLine {
+    type Err = Error;
+
+    /// Parses a type line written like Scryfall does, with an em dash before
+    /// the subtypes and `//` between faces.
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let faces = s
+            .split("//")
+            .map(TypeLineFace::parse)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| Error::InvalidTypeLine(s.to_string()))?;
+        Ok(TypeLine { faces })
+    }
+}
+
+impl fmt::Display for TypeLine {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, face) in self.faces.iter().enumerate() {
+            if i > 0 {
+                write!(f, " // ")?;
+            }
+            write!(f, "{}", face)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TypeLineFace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let types = self.supertypes.iter().chain(&self.types);
+        for (i, card_type) in types.enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", card_type)?;
+        }
+        if !self.subtypes.is_empty() {
+            write!(f, " — {}", self.subtypes.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+fn contains(types: &[String], card_type: &str) -> bool {
+    types.iter().any(|t| t.eq_ignore_ascii_case(card_type))
+}
+
+fn split_subtypes(mut subtypes: &str) -> Vec<String> {
+    let mut split = Vec::new();
+    while !subtypes.is_empty() {
+        let len = MULTI_WORD_SUBTYPES
+            .iter()
+            .find(|subtype| {
+                matches!(
+                    subtypes.strip_prefix(*subtype),
+                    Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace)
+                )
+            })
+            .map_or_else(
+                || subtypes.find(char::is_whitespace).unwrap_or(subtypes.len()),
+                |subtype| subtype.len(),
+            );
+        split.push(subtypes[..len].to_string());
+        subtypes = subtypes[len..].trim_start();
+    }
+    split
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::{TestResponse, TestServer};
+
+    #[test]
+    fn parse_type_lines() {
+        let type_line = "Legendary Snow Artifact Creature — Elf Time Lord"
+            .parse::<TypeLine>()
+            .unwrap();
+        let face = &type_line.faces()[0];
+        assert_eq!(face.supertypes, ["Legendary", "Snow"]);
+        assert_eq!(face.types, ["Artifact", "Creature"]);
+        assert_eq!(face.subtypes, ["Elf", "Time Lord"]);
+        assert!(type_line.is_artifact() && !type_line.is_land());
+        assert!(type_line.has_subtype("time lord"));
+        assert_eq!(
+            type_line.to_string(),
+            "Legendary Snow Artifact Creature — Elf Time Lord"
+        );
+
+        let type_line = "Instant // Sorcery — Adventure"
+            .parse::<TypeLine>()
+            .unwrap();
+        assert!(type_line.is_instant() && type_line.is_sorcery());
+        assert!(type_line.faces()[0].subtypes.is_empty());
+        assert_eq!(type_line.to_string(), "Instant // Sorcery — Adventure");
+
+        let type_line = "Plane — Serra's Realm".parse::<TypeLine>().unwrap();
+        assert_eq!(type_line.faces()[0].subtypes, ["Serra's Realm"]);
+
+        for invalid in &["", "Legendary — Elf", "Creature —", "Instant // "] {
+            assert!(
+                matches!(invalid.parse::<TypeLine>(), Err(Error::InvalidTypeLine(_))),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn validate_subtypes() {
+        let catalog = |path: &str, data: &str| {
+            TestResponse::json(&format!(
+                r#"{{"object":"catalog","uri":"https://api.scryfall.com/catalog/{}","total_values":2,"data":{}}}"#,
+                path, data
+            ))
+        };
+        let server = TestServer::new(vec![
+            catalog("creature-types", r#"["Elf","Druid"]"#),
+            catalog("planeswalker-types", r#"["Jace"]"#),
+            catalog("land-types", r#"["Forest","Urza's"]"#),
+            catalog("artifact-types", r#"["Equipment"]"#),
+            catalog("enchantment-types", r#"["Aura"]"#),
+            catalog("spell-types", r#"["Adventure"]"#),
+        ]);
+        let catalogs = SubtypeCatalogs::fetch_with(&server.client()).unwrap();
+        assert_eq!(server.requests()[5].path, "/catalog/spell-types");
+
+        let valid = [
+            "Creature — Elf Druid",
+            "Kindred Instant — Elf",
+            "Artifact Land — Urza's",
+            "Battle — Siege",
+        ];
+        for type_line in valid.iter() {
+            let type_line = type_line.parse::<TypeLine>().unwrap();
+            assert!(type_line.validate(&catalogs).is_ok(), "{}", type_line);
+        }
+
+        let type_line = "Creature — Elf // Land — Equipment"
+            .parse::<TypeLine>()
+            .unwrap();
+        match type_line.validate(&catalogs) {
+            Err(Error::UnknownSubtype { subtype, .. }) => assert_eq!(subtype, "Equipment"),
+            result => panic!("{:?}", result),
+        }
+
+        let partial = SubtypeCatalogs {
+            creature_types: catalogs.creature_types,
+            ..Default::default()
+        };
+        assert!(type_line.validate(&partial).is_ok());
+    }
+}
@@ -9,26 +9,39 @@
 //! the builder.
 //!
 //! [`SortMethod`], [`SortDirection`] and [`UniqueStrategy`] are options that
-//! can be set on the builder.
-//!
+//! can be set on the builder. They can also be written inside the query with
+//! an [`OptionParam`], along with the options the builder doesn't have.
 //!
 //! Finally the [`Search`] trait, defines what is a valid search for `scryfall`.
 //! It's implemented for `String` in case it's easier for the user to directly
 //! use a text representation.
-use std::fmt::Write;
+//!
+//! Params can be combined with `or`, `not` and parentheses in a [`Query`],
+//! which can also be parsed from text and evaluated against local cards with
+//! [`Matches`].
+mod matches;
+mod parser;
+mod query;
+
 use std::str;
 
-use percent_encoding::{percent_encode, CONTROLS};
 use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
 
-use crate::card::{BorderColor, Card, Colors, Frame, FrameEffect, Game, Rarity};
+use crate::card::{
+    BorderColor, Card, Colors, Decimal, Frame, FrameEffect, Game, Rarity, SecurityStamp,
+};
+use crate::client::ScryfallClient;
 use crate::format::Format;
 use crate::list::ListIter;
 use crate::set::SetCode;
 
+pub use self::matches::Matches;
+pub use self::query::Query;
+
 /// Search expresses that the implementing type can be turned into a query to
 /// `scryfall`. This means that is should be
-/// [properly encoded](https://en.wikipedia.org/wiki/Percent-encoding).
+/// [properly encoded](https://url.spec.whatwg.org/#application/x-www-form-urlencoded).
 pub trait Search {
     /// Turns a searchable into its string representation.
     fn to_query(&self) -> String;
@@ -68,10 +81,18 @@ impl Search for &str {
     ///
     /// [`Card::search`]: ../card/struct.Card.html#method.search
     fn to_query(&self) -> String {
-        format!("q={}", percent_encode(self.as_bytes(), CONTROLS))
+        encode_search(self)
     }
 }
 
+/// Encodes a search written in Scryfall's syntax as the `q` parameter of a
+/// query string.
+fn encode_search(search: &str) -> String {
+    form_urlencoded::Serializer::new(String::new())
+        .append_pair("q", search)
+        .finish()
+}
+
 /// Param expresses that the implementing type can be turned into a parameter
 /// in a scryfall search parameters. The valid parameters can be seen
 /// [here](https://scryfall.com/docs/syntax).
@@ -247,53 +268,50 @@ impl SearchBuilder {
     pub fn search(&mut self) -> crate::Result<ListIter<Card>> {
         Card::search(self)
     }
+
+    /// Same as [`search`](#method.search), but the request is made with the
+    /// given client.
+    pub fn search_with(&mut self, client: &ScryfallClient) -> crate::Result<ListIter<Card>> {
+        Card::search_with(client, self)
+    }
 }
 
 impl Search for SearchBuilder {
     fn to_query(&self) -> String {
         use itertools::Itertools;
-        let mut query = format!(
-            "{}&{}&{}&",
-            self.unique.to_param(),
-            self.sort_by.to_param(),
-            self.dir.to_param()
-        );
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        query
+            .append_pair("unique", self.unique.name())
+            .append_pair("order", self.sort_by.name())
+            .append_pair("dir", self.dir.name());
         if self.include_extras {
-            query += "include_extras=true";
+            query.append_pair("include_extras", "true");
         }
         if self.include_multilingual {
-            query += "include_variations=true";
+            query.append_pair("include_multilingual", "true");
         }
         if self.include_variations {
-            query += "include_multilingual=true";
+            query.append_pair("include_variations", "true");
         }
         if self.page > 1 {
-            query += &format!("page={}", self.page + 1);
+            query.append_pair("page", &self.page.to_string());
         }
-        query += "q=";
-        let _ = write!(
-            query,
-            "{}",
-            percent_encode(
-                self.params
-                    .iter()
-                    .map(|x| { x.to_param() })
-                    .join("+")
-                    .as_bytes(),
-                CONTROLS,
-            )
+        query.append_pair(
+            "q",
+            &self.params.iter().map(|param| param.to_param()).join(" "),
         );
-        query
+        query.finish()
     }
 }
 
 /// The unique parameter specifies if Scryfall should remove “duplicate” results
 /// in your query.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
 pub enum UniqueStrategy {
     /// Removes duplicate gameplay objects (cards that share a name and have the
     /// same functionality). For example, if your search matches more than
     /// one print of Pacifism, only one copy of Pacifism will be returned.
+    #[default]
     Cards,
     /// Returns only one copy of each unique artwork for matching cards. For
     /// example, if your search matches more than one print of Pacifism, one
@@ -307,28 +325,29 @@ pub enum UniqueStrategy {
     Prints,
 }
 
-impl Default for UniqueStrategy {
-    fn default() -> Self {
-        UniqueStrategy::Cards
+impl UniqueStrategy {
+    /// The value of the strategy in a query string.
+    fn name(self) -> &'static str {
+        use UniqueStrategy::*;
+        match self {
+            Cards => "cards",
+            Arts => "art",
+            Prints => "prints",
+        }
     }
 }
 
 impl Param for UniqueStrategy {
     fn to_param(&self) -> String {
-        use UniqueStrategy::*;
-        String::from("unique=")
-            + match self {
-                Cards => "cards",
-                Arts => "art",
-                Prints => "prints",
-            }
+        format!("unique={}", self.name())
     }
 }
 
 /// The order parameter determines how Scryfall should sort the returned cards.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
 pub enum SortMethod {
     /// Sort cards by name, A → Z
+    #[default]
     Name,
     /// Sort cards by their set and collector number: AAA/#1 → ZZZ/#999
     Set,
@@ -358,38 +377,39 @@ pub enum SortMethod {
     Artist,
 }
 
-impl Default for SortMethod {
-    fn default() -> Self {
-        SortMethod::Name
+impl SortMethod {
+    /// The value of the method in a query string.
+    fn name(self) -> &'static str {
+        use SortMethod::*;
+        match self {
+            Name => "name",
+            Set => "set",
+            Released => "released",
+            Rarity => "rarity",
+            Color => "color",
+            Usd => "usd",
+            Tix => "tix",
+            Eur => "eur",
+            Cmc => "cmc",
+            Power => "power",
+            Toughness => "toughness",
+            Edhrec => "edhrec",
+            Artist => "artist",
+        }
     }
 }
 
 impl Param for SortMethod {
     fn to_param(&self) -> String {
-        use SortMethod::*;
-        String::from("sort_by=")
-            + match self {
-                Name => "name",
-                Set => "set",
-                Released => "released",
-                Rarity => "rarity",
-                Color => "color",
-                Usd => "usd",
-                Tix => "tix",
-                Eur => "eur",
-                Cmc => "cmc",
-                Power => "power",
-                Toughness => "toughness",
-                Edhrec => "edhrec",
-                Artist => "artist",
-            }
+        format!("order={}", self.name())
     }
 }
 
 /// Which direction the sorting should occur:
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
 pub enum SortDirection {
     /// Scryfall will automatically choose the most intuitive direction to sort
+    #[default]
     Auto,
     /// Sort ascending (flip the direction of the arrows in [`SortMethod`])
     ///
@@ -401,23 +421,21 @@ pub enum SortDirection {
     Descending,
 }
 
-impl Default for SortDirection {
-    fn default() -> Self {
-        SortDirection::Auto
+impl SortDirection {
+    /// The value of the direction in a query string.
+    fn name(self) -> &'static str {
+        use SortDirection::*;
+        match self {
+            Auto => "auto",
+            Ascending => "asc",
+            Descending => "desc",
+        }
     }
 }
 
 impl Param for SortDirection {
     fn to_param(&self) -> String {
-        use SortDirection::*;
-        format!(
-            "dir={}",
-            match self {
-                Auto => "auto",
-                Ascending => "asc",
-                Descending => "desc",
-            }
-        )
+        format!("dir={}", self.name())
     }
 }
 
@@ -507,6 +525,22 @@ pub enum BooleanParam {
     SoldInPreRelease,
     /// Find cards that were given away in releases.
     SoldInRelease,
+    /// Find double-faced cards, whether they transform, meld or are modal.
+    IsDfc,
+    /// Find modal double-faced cards.
+    IsMdfc,
+    /// Find cards with an Adventure.
+    IsAdventure,
+    /// Find prints available with an etched foil finish.
+    IsEtched,
+    /// Find prints with a glossy finish.
+    IsGlossy,
+    /// Find prints without text on the art, like full-art textless promos.
+    IsTextless,
+    /// Find cards on the Commander Game Changer list.
+    IsGameChanger,
+    /// Find oversized cards, like commander displays and planes.
+    IsOversized,
 }
 
 impl Param for BooleanParam {
@@ -561,6 +595,14 @@ impl Param for BooleanParam {
                 SoldInGameDay => "gameday",
                 SoldInPreRelease => "prerelease",
                 SoldInRelease => "release",
+                IsDfc => "dfc",
+                IsMdfc => "mdfc",
+                IsAdventure => "adventure",
+                IsEtched => "etched",
+                IsGlossy => "glossy",
+                IsTextless => "textless",
+                IsGameChanger => "gamechanger",
+                IsOversized => "oversized",
             }
         )
     }
@@ -679,33 +721,80 @@ pub enum StringParam {
     LangAny,
     /// Find cards that were printed in a certain language.
     PrintedInLang(String),
+    /// Find cards whose name contains the given words.
+    Name(String),
+    /// Find cards with exactly the given name, ignoring case. Written as
+    /// `!"Lightning Bolt"`.
+    ExactName(String),
+    /// Find cards with a keyword ability or action, like `flying` or `scry`.
+    Keyword(String),
+    /// Find cards by the devotion their mana cost adds, written as the mana
+    /// symbols counted, like `{u/b}{u/b}{u/b}` or `ggg`. A `:` searches for at
+    /// least that much devotion.
+    Devotion(ComparisonExpr, String),
+    /// Find cards by collector number, which can be a range or a number that
+    /// isn't only digits, like `12a` or `★`. Use [`NumericParam::CollectorNumber`]
+    /// for exact numbers.
+    ///
+    /// [`NumericParam::CollectorNumber`]: enum.NumericParam.html#variant.CollectorNumber
+    CollectorNumber(ComparisonExpr, String),
+    /// Find cards by a tag on their illustration, as kept by
+    /// [Scryfall Tagger](https://tagger.scryfall.com). Written as `art:`.
+    ArtTag(String),
+    /// Find cards by a tag on their function, as kept by
+    /// [Scryfall Tagger](https://tagger.scryfall.com). Written as `function:`.
+    OracleTag(String),
 }
 
 impl Param for StringParam {
     fn to_param(&self) -> String {
         use StringParam::*;
         match self {
-            ManaCost(s) => format!("m:{}", s),
-            Type(s) => format!("t:{}", s),
-            Oracle(s) => format!("o:\"{}\"", s),
-            OracleFull(s) => format!("fo:\"{}\"", s),
+            ManaCost(s) => format!("m:{}", quoted(s)),
+            Type(s) => format!("t:{}", quoted(s)),
+            Oracle(s) => format!("o:{}", quote(s)),
+            OracleFull(s) => format!("fo:{}", quote(s)),
             Power(c, s) => format!("pow{}{}", c, s),
             Toughness(c, s) => format!("tou{}{}", c, s),
             Loyalty(c, s) => format!("loy{}{}", c, s),
             Set(s) => format!("s:{}", s),
             Block(s) => format!("b:{}", s),
             WasInSet(s) => format!("in:{}", s),
-            InCube(s) => format!("cube:{}", s),
-            Artist(s) => format!("a:{}", s),
-            Flavor(s) => format!("ft:{}", s),
-            WaterMark(s) => format!("wt:{}", s),
+            InCube(s) => format!("cube:{}", quoted(s)),
+            Artist(s) => format!("a:{}", quoted(s)),
+            Flavor(s) => format!("ft:{}", quoted(s)),
+            WaterMark(s) => format!("wt:{}", quoted(s)),
             Lang(s) => format!("lang:{}", s),
             LangAny => "lang:any".to_string(),
             PrintedInLang(s) => format!("in:{}", s),
+            Name(s) => format!("name:{}", quoted(s)),
+            ExactName(s) => format!("!{}", quoted(s)),
+            Keyword(s) => format!("keyword:{}", quoted(s)),
+            Devotion(c, s) => format!("devotion{}{}", c, s),
+            CollectorNumber(c, s) => format!("cn{}{}", c, s),
+            ArtTag(s) => format!("art:{}", quoted(s)),
+            OracleTag(s) => format!("function:{}", quoted(s)),
         }
     }
 }
 
+/// Puts a value between quotes if it wouldn't be read as a single word.
+fn quoted(value: &str) -> String {
+    if value.is_empty()
+        || value.starts_with('/')
+        || value.contains(|c: char| c.is_whitespace() || "()\"".contains(c))
+    {
+        quote(value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// Puts a value between quotes, escaping the quotes and backslashes in it.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 /// A parameter that takes a number as its value.
 #[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum NumericParam {
@@ -716,12 +805,12 @@ pub enum NumericParam {
     ///
     /// [`StringParam::Set`]: enum.StringParam.html#variant.Set
     CollectorNumber(usize),
-    /// Find cards by price in tix.
-    TixPrice(ComparisonExpr, usize),
-    /// Find cards by price in euros.
-    EurPrice(ComparisonExpr, usize),
-    /// Find cards by price in usd.
-    UsdPrice(ComparisonExpr, usize),
+    /// Find cards by price in tix, like `0.05`.
+    TixPrice(ComparisonExpr, Decimal),
+    /// Find cards by price in euros, like `1.50`.
+    EurPrice(ComparisonExpr, Decimal),
+    /// Find cards by price in usd, like `0.25`.
+    UsdPrice(ComparisonExpr, Decimal),
     /// Find cards by the number of times a card has been printed.
     Prints(ComparisonExpr, usize),
     /// Find by number of sets a card has been in.
@@ -730,6 +819,15 @@ pub enum NumericParam {
     PaperPrints(ComparisonExpr, usize),
     /// Find by number of paper sets a card has been in.
     PaperSets(ComparisonExpr, usize),
+    /// Find cards by their power plus their toughness.
+    PowTou(ComparisonExpr, usize),
+    /// Find the print with the given id on MTG Arena.
+    ArenaId(usize),
+    /// Find the print with the given id on Magic: The Gathering Online, foil
+    /// or not.
+    MtgoId(usize),
+    /// Find the print with the given id on Gatherer.
+    MultiverseId(usize),
 }
 
 impl Param for NumericParam {
@@ -745,12 +843,16 @@ impl Param for NumericParam {
             Sets(c, n) => format!("sets{}{}", c, n),
             PaperPrints(c, n) => format!("paperprints{}{}", c, n),
             PaperSets(c, n) => format!("papersets{}{}", c, n),
+            PowTou(c, n) => format!("pt{}{}", c, n),
+            ArenaId(n) => format!("arenaid:{}", n),
+            MtgoId(n) => format!("mtgoid:{}", n),
+            MultiverseId(n) => format!("multiverseid:{}", n),
         }
     }
 }
 
 /// Find cards by their print rarity.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct RarityParam(
     /// How to filter for the rarity
     pub ComparisonExpr,
@@ -763,11 +865,14 @@ impl Param for RarityParam {
         format!(
             "r{}{}",
             self.0,
-            match self.1 {
+            match &self.1 {
                 Rarity::Common => "c",
                 Rarity::Uncommon => "u",
                 Rarity::Rare => "r",
                 Rarity::Mythic => "m",
+                Rarity::Special => "s",
+                Rarity::Bonus => "b",
+                Rarity::Other(name) => name,
             }
         )
     }
@@ -780,20 +885,24 @@ pub enum ColorParam {
     Color(ComparisonExpr, Colors),
     /// Find cards by their color identity.
     ColorIdentity(ComparisonExpr, Colors),
+    /// Find cards by the colors of mana they produce. `c` searches for cards
+    /// that produce colorless mana.
+    Produces(ComparisonExpr, Colors),
 }
 
 impl Param for ColorParam {
     fn to_param(&self) -> String {
         use ColorParam::*;
         match self {
-            Color(ce, cl) => format!("c{}{}", cl, ce),
-            ColorIdentity(ce, cl) => format!("id{}{}", cl, ce),
+            Color(ce, cl) => format!("c{}{}", ce, cl),
+            ColorIdentity(ce, cl) => format!("id{}{}", ce, cl),
+            Produces(ce, cl) => format!("produces{}{}", ce, cl),
         }
     }
 }
 
 /// A parameter that takes a format as its value.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum FormatParam {
     /// Find cards legal in a format.
     Legal(Format),
@@ -832,8 +941,14 @@ impl Param for FrameEffect {
     }
 }
 
+impl Param for SecurityStamp {
+    fn to_param(&self) -> String {
+        format!("stamp:{}", self)
+    }
+}
+
 /// A parameter that takes a game mode as its value.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 pub enum GameParam {
     /// Find specific prints available in different Magic game environments
     Game(Game),
@@ -873,6 +988,163 @@ impl Param for TimeParam {
     }
 }
 
+/// A parameter that takes a regular expression as its value. The expression is
+/// written without the `/` that delimit it, and uses Scryfall's
+/// [regex syntax](https://scryfall.com/docs/regular-expressions).
+///
+/// ```rust
+/// use scryfall::card_searcher::{Param, RegexParam};
+///
+/// assert_eq!(RegexParam::Oracle("^{T}:".into()).to_param(), "o:/^{T}:/")
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RegexParam {
+    /// Find cards whose name matches the expression.
+    Name(String),
+    /// Find cards whose type line matches the expression.
+    Type(String),
+    /// Find cards whose Oracle text matches the expression.
+    Oracle(String),
+    /// Find cards whose full Oracle text, with reminder text, matches the
+    /// expression.
+    OracleFull(String),
+    /// Find cards whose flavor text matches the expression.
+    Flavor(String),
+}
+
+impl Param for RegexParam {
+    fn to_param(&self) -> String {
+        use RegexParam::*;
+        match self {
+            Name(r) => format!("name:/{}/", r),
+            Type(r) => format!("t:/{}/", r),
+            Oracle(r) => format!("o:/{}/", r),
+            OracleFull(r) => format!("fo:/{}/", r),
+            Flavor(r) => format!("ft:/{}/", r),
+        }
+    }
+}
+
+/// Options written inside the query itself. They change how the results are
+/// returned, not which cards match, and override the settings of the
+/// [`SearchBuilder`].
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum OptionParam {
+    /// How to sort the results, like [`SearchBuilder::sorting_by`].
+    ///
+    /// [`SearchBuilder::sorting_by`]: struct.SearchBuilder.html#method.sorting_by
+    Order(SortMethod),
+    /// The direction to sort the results in, like
+    /// [`SearchBuilder::with_sort_direction`].
+    ///
+    /// [`SearchBuilder::with_sort_direction`]: struct.SearchBuilder.html#method.with_sort_direction
+    Direction(SortDirection),
+    /// How to remove duplicate results, like
+    /// [`SearchBuilder::with_unique_strategy`].
+    ///
+    /// [`SearchBuilder::with_unique_strategy`]: struct.SearchBuilder.html#method.with_unique_strategy
+    Unique(UniqueStrategy),
+    /// Which print of each card to show.
+    Prefer(PreferStrategy),
+    /// How the results are shown on the website.
+    Display(DisplayMode),
+    /// Include extra cards, like tokens and planes, like
+    /// [`SearchBuilder::including_extras`].
+    ///
+    /// [`SearchBuilder::including_extras`]: struct.SearchBuilder.html#method.including_extras
+    IncludeExtras,
+}
+
+impl Param for OptionParam {
+    fn to_param(&self) -> String {
+        use OptionParam::*;
+        match self {
+            Order(method) => format!("order:{}", method.name()),
+            Direction(dir) => format!("direction:{}", dir.name()),
+            Unique(strategy) => format!("unique:{}", strategy.name()),
+            Prefer(strategy) => format!("prefer:{}", strategy.name()),
+            Display(mode) => format!("display:{}", mode.name()),
+            IncludeExtras => "include:extras".to_string(),
+        }
+    }
+}
+
+/// Which print of each card Scryfall shows when it removes duplicates.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PreferStrategy {
+    /// The oldest print.
+    Oldest,
+    /// The newest print.
+    Newest,
+    /// The cheapest print in usd.
+    UsdLow,
+    /// The most expensive print in usd.
+    UsdHigh,
+    /// The cheapest print in euros.
+    EurLow,
+    /// The most expensive print in euros.
+    EurHigh,
+    /// The cheapest print in tix.
+    TixLow,
+    /// The most expensive print in tix.
+    TixHigh,
+    /// A promotional print.
+    Promo,
+    /// A print with an unusual frame or border.
+    Atypical,
+    /// A Universes Beyond print.
+    UniversesBeyond,
+    /// A print that isn't Universes Beyond.
+    NotUniversesBeyond,
+}
+
+impl PreferStrategy {
+    /// The value of the strategy in a query.
+    fn name(self) -> &'static str {
+        use PreferStrategy::*;
+        match self {
+            Oldest => "oldest",
+            Newest => "newest",
+            UsdLow => "usd-low",
+            UsdHigh => "usd-high",
+            EurLow => "eur-low",
+            EurHigh => "eur-high",
+            TixLow => "tix-low",
+            TixHigh => "tix-high",
+            Promo => "promo",
+            Atypical => "atypical",
+            UniversesBeyond => "ub",
+            NotUniversesBeyond => "notub",
+        }
+    }
+}
+
+/// How Scryfall shows the results on the website.
+#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum DisplayMode {
+    /// The card images.
+    Grid,
+    /// A table with a row for each card.
+    Checklist,
+    /// The card images along with their text.
+    Full,
+    /// The text of the cards only.
+    Text,
+}
+
+impl DisplayMode {
+    /// The value of the mode in a query.
+    fn name(self) -> &'static str {
+        use DisplayMode::*;
+        match self {
+            Grid => "grid",
+            Checklist => "checklist",
+            Full => "full",
+            Text => "text",
+        }
+    }
+}
+
 /// The negative version of a param, for example, "is:spell" becomes "-is:spell"
 ///
 /// ```rust
@@ -895,3 +1167,444 @@ impl<T: Param> Param for NotParam<T> {
         format!("-{}", self.0.to_param())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use chrono::NaiveDate;
+
+    use super::ComparisonExpr::*;
+    use super::*;
+    use crate::card::Color;
+
+    fn set(code: &str) -> SetCode {
+        SetCode::try_from(code).unwrap()
+    }
+
+    fn assert_params(cases: Vec<(Box<dyn Param>, &str)>) {
+        for (param, expected) in cases {
+            assert_eq!(param.to_param(), expected);
+        }
+    }
+
+    /// The pairs of a query string, decoded.
+    fn pairs(query: &str) -> Vec<(String, String)> {
+        form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect()
+    }
+
+    #[test]
+    fn boolean_params() {
+        use BooleanParam::*;
+        assert_params(vec![
+            (ColorIndicator.into(), "has:indicator"),
+            (WaterMark.into(), "has:watermark"),
+            (NewRarity.into(), "new:rarity"),
+            (NewArt.into(), "new:art"),
+            (NewArtist.into(), "new:artist"),
+            (NewFlavor.into(), "new:flavor"),
+            (NewFrame.into(), "new:frame"),
+            (NewLanguage.into(), "new:language"),
+            (IsPhyrexian.into(), "is:phyrexian"),
+            (IsHybrid.into(), "is:hybrid"),
+            (IsSplit.into(), "is:split"),
+            (IsFlip.into(), "is:flip"),
+            (IsTransform.into(), "is:transform"),
+            (IsMeld.into(), "is:meld"),
+            (IsLeveler.into(), "is:leveler"),
+            (IsSpell.into(), "is:spell"),
+            (IsPermanent.into(), "is:permanent"),
+            (IsHistoric.into(), "is:historic"),
+            (IsModal.into(), "is:modal"),
+            (IsVanilla.into(), "is:vanilla"),
+            (IsFunny.into(), "is:funny"),
+            (IsCommander.into(), "is:commander"),
+            (IsReserved.into(), "is:reserved"),
+            (IsFull.into(), "is:full"),
+            (IsNonFoil.into(), "is:nonfoil"),
+            (IsFoil.into(), "is:foil"),
+            (IsHires.into(), "is:hires"),
+            (IsDigital.into(), "is:digital"),
+            (IsPromo.into(), "is:promo"),
+            (IsSpotlight.into(), "is:spotlight"),
+            (IsUnique.into(), "is:unique"),
+            (IsReprint.into(), "is:reprint"),
+            (SoldInBoosters.into(), "is:boosters"),
+            (SoldInPwDecks.into(), "is:planeswalker_deck"),
+            (SoldInLeague.into(), "is:league"),
+            (SoldInBuyABox.into(), "is:buyabox"),
+            (SoldInGiftBox.into(), "is:giftbox"),
+            (SoldInIntroPack.into(), "is:intro_pack"),
+            (SoldInGameDay.into(), "is:gameday"),
+            (SoldInPreRelease.into(), "is:prerelease"),
+            (SoldInRelease.into(), "is:release"),
+            (IsDfc.into(), "is:dfc"),
+            (IsMdfc.into(), "is:mdfc"),
+            (IsAdventure.into(), "is:adventure"),
+            (IsEtched.into(), "is:etched"),
+            (IsGlossy.into(), "is:glossy"),
+            (IsTextless.into(), "is:textless"),
+            (IsGameChanger.into(), "is:gamechanger"),
+            (IsOversized.into(), "is:oversized"),
+        ]);
+    }
+
+    #[test]
+    fn string_params() {
+        use StringParam::*;
+        assert_params(vec![
+            (ManaCost("{2}{R}{R}".into()).into(), "m:{2}{R}{R}"),
+            (Type("goblin".into()).into(), "t:goblin"),
+            (
+                Type("legendary goblin".into()).into(),
+                "t:\"legendary goblin\"",
+            ),
+            (Oracle("draw a card".into()).into(), "o:\"draw a card\""),
+            (OracleFull("~ deals".into()).into(), "fo:\"~ deals\""),
+            (Oracle("a\\".into()).into(), "o:\"a\\\\\""),
+            (ManaCost("{2} {R}".into()).into(), "m:\"{2} {R}\""),
+            (
+                Artist("Jim \"Bo\" Neil".into()).into(),
+                "a:\"Jim \\\"Bo\\\" Neil\"",
+            ),
+            (Power(AtLeast, "3".into()).into(), "pow>3"),
+            (Toughness(AtMostInclusive, "*".into()).into(), "tou<=*"),
+            (Loyalty(Is, "4".into()).into(), "loy=4"),
+            (Set(set("war")).into(), "s:war"),
+            (Block(set("grn")).into(), "b:grn"),
+            (WasInSet(set("lea")).into(), "in:lea"),
+            (InCube("vintage".into()).into(), "cube:vintage"),
+            (Artist("Rebecca Guay".into()).into(), "a:\"Rebecca Guay\""),
+            (Flavor("(Reminder)".into()).into(), "ft:\"(Reminder)\""),
+            (WaterMark("orzhov".into()).into(), "wt:orzhov"),
+            (WaterMark(String::new()).into(), "wt:\"\""),
+            (Lang("ja".into()).into(), "lang:ja"),
+            (LangAny.into(), "lang:any"),
+            (PrintedInLang("ru".into()).into(), "in:ru"),
+            (Name("bolt".into()).into(), "name:bolt"),
+            (Name("goblin king".into()).into(), "name:\"goblin king\""),
+            (ExactName("Fire".into()).into(), "!Fire"),
+            (
+                ExactName("Lightning Bolt".into()).into(),
+                "!\"Lightning Bolt\"",
+            ),
+            (
+                Keyword("first strike".into()).into(),
+                "keyword:\"first strike\"",
+            ),
+            (
+                Devotion(AtLeastInclusive, "{u/b}{u/b}".into()).into(),
+                "devotion>={u/b}{u/b}",
+            ),
+            (Devotion(Is, "ggg".into()).into(), "devotion=ggg"),
+            (CollectorNumber(AtLeast, "50".into()).into(), "cn>50"),
+            (CollectorNumber(Is, "12a".into()).into(), "cn=12a"),
+            (ArtTag("squirrel".into()).into(), "art:squirrel"),
+            (OracleTag("removal".into()).into(), "function:removal"),
+        ]);
+    }
+
+    #[test]
+    fn numeric_params() {
+        use NumericParam::*;
+        assert_params(vec![
+            (Cmc(AtLeast, 3).into(), "cmc>3"),
+            (Cmc(AtLeastInclusive, 3).into(), "cmc>=3"),
+            (Cmc(AtMost, 3).into(), "cmc<3"),
+            (Cmc(AtMostInclusive, 3).into(), "cmc<=3"),
+            (Cmc(Is, 3).into(), "cmc=3"),
+            (Cmc(IsNot, 3).into(), "cmc!=3"),
+            (CollectorNumber(123).into(), "cn:123"),
+            (TixPrice(AtMost, Decimal::new(5, 2)).into(), "tix<0.05"),
+            (EurPrice(AtLeast, Decimal::from(10)).into(), "eur>10"),
+            (
+                UsdPrice(AtMostInclusive, Decimal::new(250, 2)).into(),
+                "usd<=2.50",
+            ),
+            (UsdPrice(Is, Decimal::new(5, 1)).into(), "usd=0.5"),
+            (Prints(Is, 1).into(), "prints=1"),
+            (Sets(AtLeast, 20).into(), "sets>20"),
+            (PaperPrints(IsNot, 2).into(), "paperprints!=2"),
+            (PaperSets(AtMost, 4).into(), "papersets<4"),
+            (PowTou(AtLeastInclusive, 10).into(), "pt>=10"),
+            (ArenaId(67330).into(), "arenaid:67330"),
+            (MtgoId(81940).into(), "mtgoid:81940"),
+            (MultiverseId(442130).into(), "multiverseid:442130"),
+        ]);
+    }
+
+    #[test]
+    fn other_params() {
+        let colors = |colors: &[Color]| Colors::from(colors);
+        assert_params(vec![
+            (RarityParam(Is, Rarity::Common).into(), "r=c"),
+            (RarityParam(AtLeast, Rarity::Uncommon).into(), "r>u"),
+            (RarityParam(AtLeastInclusive, Rarity::Rare).into(), "r>=r"),
+            (RarityParam(IsNot, Rarity::Mythic).into(), "r!=m"),
+            (RarityParam(AtMost, Rarity::Special).into(), "r<s"),
+            (RarityParam(Is, Rarity::Bonus).into(), "r=b"),
+            (
+                ColorParam::Color(AtLeastInclusive, colors(&[Color::White, Color::Blue])).into(),
+                "c>=wu",
+            ),
+            (ColorParam::Color(Is, Colors::multicolored()).into(), "c=m"),
+            (
+                ColorParam::Produces(AtLeastInclusive, colors(&[Color::Green])).into(),
+                "produces>=g",
+            ),
+            (
+                ColorParam::Produces(Is, Colors::colorless()).into(),
+                "produces=c",
+            ),
+            (
+                ColorParam::ColorIdentity(AtMostInclusive, Colors::colorless()).into(),
+                "id<=c",
+            ),
+            (
+                ColorParam::ColorIdentity(Is, colors(&[Color::Black, Color::Red, Color::Green]))
+                    .into(),
+                "id=brg",
+            ),
+            (FormatParam::Legal(Format::Modern).into(), "legal:modern"),
+            (
+                FormatParam::Banned(Format::Commander).into(),
+                "banned:commander",
+            ),
+            (
+                FormatParam::Restricted(Format::Vintage).into(),
+                "restricted:vintage",
+            ),
+            (BorderColor::Borderless.into(), "border:borderless"),
+            (BorderColor::Silver.into(), "border:silver"),
+            (Frame::Y1997.into(), "frame:1997"),
+            (Frame::Future.into(), "frame:future"),
+            (FrameEffect::Showcase.into(), "frame:showcase"),
+            (FrameEffect::ExtendedArt.into(), "frame:extendedart"),
+            (SecurityStamp::Oval.into(), "stamp:oval"),
+            (SecurityStamp::Acorn.into(), "stamp:acorn"),
+            (RegexParam::Name("^goblin".into()).into(), "name:/^goblin/"),
+            (RegexParam::Type("\\bgod\\b".into()).into(), "t:/\\bgod\\b/"),
+            (RegexParam::Oracle("^{T}:".into()).into(), "o:/^{T}:/"),
+            (
+                RegexParam::OracleFull("\\(.*\\)".into()).into(),
+                "fo:/\\(.*\\)/",
+            ),
+            (RegexParam::Flavor("^\"".into()).into(), "ft:/^\"/"),
+            (GameParam::Game(Game::Arena).into(), "game:arena"),
+            (GameParam::InGame(Game::Mtgo).into(), "in:mtgo"),
+            (TimeParam::Year(AtMostInclusive, 1994).into(), "year<=1994"),
+            (
+                TimeParam::Date(AtLeast, NaiveDate::from_ymd_opt(2015, 8, 18).unwrap()).into(),
+                "date>2015-08-18",
+            ),
+            (
+                TimeParam::Set(AtLeastInclusive, set("war")).into(),
+                "date>=war",
+            ),
+            (not(BooleanParam::IsSpell).into(), "-is:spell"),
+            (not(not(BooleanParam::IsSpell)).into(), "--is:spell"),
+            (
+                (Query::from(BooleanParam::IsFunny) | StringParam::Type("elf".into()).into())
+                    .into(),
+                "(is:funny or t:elf)",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn option_params() {
+        use OptionParam::*;
+        let mut cases: Vec<(Box<dyn Param>, &str)> = vec![
+            (Order(SortMethod::Cmc).into(), "order:cmc"),
+            (Order(SortMethod::Edhrec).into(), "order:edhrec"),
+            (Direction(SortDirection::Auto).into(), "direction:auto"),
+            (Direction(SortDirection::Ascending).into(), "direction:asc"),
+            (
+                Direction(SortDirection::Descending).into(),
+                "direction:desc",
+            ),
+            (Unique(UniqueStrategy::Cards).into(), "unique:cards"),
+            (Unique(UniqueStrategy::Arts).into(), "unique:art"),
+            (Unique(UniqueStrategy::Prints).into(), "unique:prints"),
+            (Display(DisplayMode::Grid).into(), "display:grid"),
+            (Display(DisplayMode::Checklist).into(), "display:checklist"),
+            (Display(DisplayMode::Full).into(), "display:full"),
+            (Display(DisplayMode::Text).into(), "display:text"),
+            (IncludeExtras.into(), "include:extras"),
+        ];
+        {
+            use PreferStrategy::*;
+            cases.extend(vec![
+                (Prefer(Oldest).into(), "prefer:oldest"),
+                (Prefer(Newest).into(), "prefer:newest"),
+                (Prefer(UsdLow).into(), "prefer:usd-low"),
+                (Prefer(UsdHigh).into(), "prefer:usd-high"),
+                (Prefer(EurLow).into(), "prefer:eur-low"),
+                (Prefer(EurHigh).into(), "prefer:eur-high"),
+                (Prefer(TixLow).into(), "prefer:tix-low"),
+                (Prefer(TixHigh).into(), "prefer:tix-high"),
+                (Prefer(Promo).into(), "prefer:promo"),
+                (Prefer(Atypical).into(), "prefer:atypical"),
+                (Prefer(UniversesBeyond).into(), "prefer:ub"),
+                (Prefer(NotUniversesBeyond).into(), "prefer:notub"),
+            ]);
+        }
+        assert_params(cases);
+
+        let mut search = SearchBuilder::new();
+        search
+            .param(StringParam::Type("goblin".into()))
+            .param(Prefer(PreferStrategy::UsdLow))
+            .param(IncludeExtras);
+        assert_eq!(
+            search.to_query(),
+            "unique=cards&order=name&dir=auto&q=t%3Agoblin+prefer%3Ausd-low+include%3Aextras"
+        );
+    }
+
+    #[test]
+    fn default_options() {
+        let mut search = SearchBuilder::new();
+        search.param(NumericParam::Cmc(Is, 2));
+        assert_eq!(
+            search.to_query(),
+            "unique=cards&order=name&dir=auto&q=cmc%3D2"
+        );
+        assert_eq!(
+            SearchBuilder::new().to_query(),
+            "unique=cards&order=name&dir=auto&q="
+        );
+    }
+
+    #[test]
+    fn sorting_options() {
+        use SortMethod::*;
+        let methods = [
+            (Name, "name"),
+            (Set, "set"),
+            (Released, "released"),
+            (Rarity, "rarity"),
+            (Color, "color"),
+            (Usd, "usd"),
+            (Tix, "tix"),
+            (Eur, "eur"),
+            (Cmc, "cmc"),
+            (Power, "power"),
+            (Toughness, "toughness"),
+            (Edhrec, "edhrec"),
+            (Artist, "artist"),
+        ];
+        for (method, name) in methods {
+            assert_eq!(method.to_param(), format!("order={}", name));
+            let mut search = SearchBuilder::new();
+            search.sorting_by(method);
+            assert_eq!(
+                search.to_query(),
+                format!("unique=cards&order={}&dir=auto&q=", name)
+            );
+        }
+
+        let strategies = [
+            (UniqueStrategy::Cards, "cards"),
+            (UniqueStrategy::Arts, "art"),
+            (UniqueStrategy::Prints, "prints"),
+        ];
+        let directions = [
+            (SortDirection::Auto, "auto"),
+            (SortDirection::Ascending, "asc"),
+            (SortDirection::Descending, "desc"),
+        ];
+        for (strategy, unique) in strategies {
+            assert_eq!(strategy.to_param(), format!("unique={}", unique));
+            for (direction, dir) in directions {
+                assert_eq!(direction.to_param(), format!("dir={}", dir));
+                let mut search = SearchBuilder::new();
+                search
+                    .with_unique_strategy(strategy)
+                    .with_sort_direction(direction);
+                assert_eq!(
+                    search.to_query(),
+                    format!("unique={}&order=name&dir={}&q=", unique, dir)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn include_flags() {
+        for flags in 0..8 {
+            let mut search = SearchBuilder::new();
+            let mut expected = vec![("unique", "cards"), ("order", "name"), ("dir", "auto")];
+            if flags & 1 != 0 {
+                search.including_extras();
+                expected.push(("include_extras", "true"));
+            }
+            if flags & 2 != 0 {
+                search.including_multilingual();
+                expected.push(("include_multilingual", "true"));
+            }
+            if flags & 4 != 0 {
+                search.including_variations();
+                expected.push(("include_variations", "true"));
+            }
+            expected.push(("q", "t:goblin"));
+            search.param(StringParam::Type("goblin".into()));
+
+            let expected: Vec<_> = expected
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect();
+            assert_eq!(pairs(&search.to_query()), expected);
+        }
+
+        let mut search = SearchBuilder::new();
+        search
+            .including_extras()
+            .including_multilingual()
+            .including_variations();
+        assert_eq!(
+            search.to_query(),
+            "unique=cards&order=name&dir=auto&include_extras=true\
+             &include_multilingual=true&include_variations=true&q="
+        );
+    }
+
+    #[test]
+    fn page_option() {
+        let mut search = SearchBuilder::new();
+        search.on_page(1);
+        assert_eq!(search.to_query(), "unique=cards&order=name&dir=auto&q=");
+        search.on_page(3);
+        assert_eq!(
+            search.to_query(),
+            "unique=cards&order=name&dir=auto&page=3&q="
+        );
+    }
+
+    #[test]
+    fn escape_the_query() {
+        let mut search = SearchBuilder::new();
+        search
+            .param(StringParam::Oracle("+1/+1 & more".into()))
+            .param(StringParam::Artist("Jim O'Neil".into()))
+            .param(StringParam::ManaCost("{W/P}".into()))
+            .param(not(BooleanParam::IsFunny));
+        let query = search.to_query();
+        assert_eq!(
+            query,
+            "unique=cards&order=name&dir=auto\
+             &q=o%3A%22%2B1%2F%2B1+%26+more%22+a%3A%22Jim+O%27Neil%22+m%3A%7BW%2FP%7D+-is%3Afunny"
+        );
+        assert_eq!(
+            pairs(&query).last().unwrap().1,
+            "o:\"+1/+1 & more\" a:\"Jim O'Neil\" m:{W/P} -is:funny"
+        );
+        assert_eq!("cmc=2 & t:elf".to_query(), "q=cmc%3D2+%26+t%3Aelf");
+        assert_eq!(
+            pairs(&"a+b&c=d".to_query()),
+            vec![("q".to_string(), "a+b&c=d".to_string())]
+        );
+    }
+}
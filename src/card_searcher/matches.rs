@@ -0,0 +1,797 @@
+//! Evaluation of search queries against cards, without asking Scryfall.
+use chrono::Datelike;
+
+use crate::card::{
+    BorderColor, Card, CardFace, Color, Colors, Decimal, Finish, Frame, FrameEffect, Layout,
+    Legality, Price, SecurityStamp, TypeLine, TypeLineFace,
+};
+use crate::card_searcher::{
+    BooleanParam, ColorParam, ComparisonExpr, FormatParam, GameParam, NumericParam, OptionParam,
+    Param, Query, RarityParam, RegexParam, SearchBuilder, StringParam, TimeParam,
+};
+use crate::error::Error;
+use crate::set::SetType;
+use crate::symbology::{ManaCost, ManaSymbol};
+
+/// A search that can be checked against a card locally, like Scryfall would
+/// on its servers.
+///
+/// Only what can be derived from the card itself can be evaluated. Params that
+/// need other printings of the card (`new:`, `prints`, `in:`, ...), data the
+/// card doesn't have (`block:`, `cube:`, tags) or regular expressions can't, and
+/// make [`matches`](#tymethod.matches) fail with
+/// [`Error::UnsupportedQuery`], naming them. [`Query::unsupported`] lists them
+/// up front. Options like `order:` don't filter cards, so they match any card.
+///
+/// # Examples
+/// ```rust,no_run
+/// use scryfall::bulk;
+/// use scryfall::card_searcher::{Matches, Query};
+///
+/// let query = Query::parse("(t:goblin or t:elf) cmc<=2 id<=r").unwrap();
+/// let cards = bulk::oracle_cards().unwrap();
+/// let goblins = cards
+///     .iter()
+///     .filter(|card| query.matches(card).unwrap())
+///     .collect::<Vec<_>>();
+/// ```
+pub trait Matches {
+    /// Checks if `card` matches the search.
+    fn matches(&self, card: &Card) -> crate::Result<bool>;
+}
+
+impl Matches for Query {
+    fn matches(&self, card: &Card) -> crate::Result<bool> {
+        let unsupported = self.unsupported();
+        if !unsupported.is_empty() {
+            return Err(Error::UnsupportedQuery(
+                unsupported.iter().map(|query| query.to_param()).collect(),
+            ));
+        }
+        Ok(evaluate(self, card))
+    }
+}
+
+/// The params are parsed back from their text with [`Query::parse`], so any
+/// [`Param`] can be evaluated if its syntax is supported. The settings of the
+/// builder, like the sort order, are ignored.
+impl Matches for SearchBuilder {
+    fn matches(&self, card: &Card) -> crate::Result<bool> {
+        let params = self
+            .params
+            .iter()
+            .map(|param| Query::parse(&param.to_param()))
+            .collect::<crate::Result<Vec<_>>>()?;
+        Query::And(params).matches(card)
+    }
+}
+
+macro_rules! impl_matches {
+    ($($param:ty),* $(,)?) => {
+        $(
+            impl Matches for $param {
+                fn matches(&self, card: &Card) -> crate::Result<bool> {
+                    Query::from(self.clone()).matches(card)
+                }
+            }
+        )*
+    };
+}
+
+impl_matches! {
+    BooleanParam,
+    StringParam,
+    NumericParam,
+    RarityParam,
+    ColorParam,
+    FormatParam,
+    BorderColor,
+    Frame,
+    FrameEffect,
+    GameParam,
+    TimeParam,
+    SecurityStamp,
+    RegexParam,
+    OptionParam,
+}
+
+impl Query {
+    /// The params of this query that can't be evaluated offline by
+    /// [`Matches`].
+    pub fn unsupported(&self) -> Vec<&Query> {
+        match self {
+            Query::And(queries) | Query::Or(queries) => {
+                queries.iter().flat_map(Query::unsupported).collect()
+            },
+            Query::Not(query) => query.unsupported(),
+            query if is_supported(query) => Vec::new(),
+            query => vec![query],
+        }
+    }
+}
+
+fn is_supported(query: &Query) -> bool {
+    use BooleanParam::*;
+    match query {
+        Query::Boolean(param) => !matches!(
+            param,
+            NewRarity | NewArt | NewArtist | NewFlavor | NewFrame | NewLanguage | IsUnique
+        ),
+        Query::String(param) => !matches!(
+            param,
+            StringParam::Block(_)
+                | StringParam::WasInSet(_)
+                | StringParam::InCube(_)
+                | StringParam::PrintedInLang(_)
+                | StringParam::ArtTag(_)
+                | StringParam::OracleTag(_)
+        ),
+        Query::Numeric(param) => !matches!(
+            param,
+            NumericParam::Prints(..)
+                | NumericParam::Sets(..)
+                | NumericParam::PaperPrints(..)
+                | NumericParam::PaperSets(..)
+        ),
+        Query::Game(GameParam::InGame(_)) | Query::Time(TimeParam::Set(..)) | Query::Regex(_) => {
+            false
+        },
+        Query::Custom(term) => name_term(term).is_some(),
+        _ => true,
+    }
+}
+
+/// A term that searches card names: a word, a quoted phrase or an exact name.
+enum NameTerm<'a> {
+    Contains(&'a str),
+    Exact(&'a str),
+}
+
+fn unquote(term: &str) -> Option<&str> {
+    term.strip_prefix('"')?.strip_suffix('"')
+}
+
+fn name_term(term: &str) -> Option<NameTerm<'_>> {
+    if let Some(name) = term.strip_prefix('!') {
+        return Some(NameTerm::Exact(unquote(name).unwrap_or(name)));
+    }
+    if let Some(phrase) = unquote(term) {
+        return Some(NameTerm::Contains(phrase));
+    }
+    if term.is_empty() || term.contains(|c| ":=<>!/\"".contains(c)) {
+        return None;
+    }
+    Some(NameTerm::Contains(term))
+}
+
+/// Evaluates a query whose params are all supported.
+fn evaluate(query: &Query, card: &Card) -> bool {
+    match query {
+        Query::And(queries) => queries.iter().all(|query| evaluate(query, card)),
+        Query::Or(queries) => queries.iter().any(|query| evaluate(query, card)),
+        Query::Not(query) => !evaluate(query, card),
+        Query::Boolean(param) => boolean(param, card),
+        Query::String(param) => string(param, card),
+        Query::Numeric(param) => numeric(param, card),
+        Query::Rarity(RarityParam(op, rarity)) => compare(*op, &card.rarity, rarity),
+        Query::Color(ColorParam::Color(op, colors)) => {
+            compare_colors(*op, &card_colors(card), *colors)
+        },
+        Query::Color(ColorParam::ColorIdentity(op, colors)) => {
+            compare_colors(*op, &card.color_identity, *colors)
+        },
+        Query::Color(ColorParam::Produces(op, colors)) => produces(*op, card, *colors),
+        Query::Format(param) => match param {
+            FormatParam::Legal(format) => card.legalities.is_legal(format),
+            FormatParam::Banned(format) => card.legalities.get(format) == Some(Legality::Banned),
+            FormatParam::Restricted(format) => {
+                card.legalities.get(format) == Some(Legality::Restricted)
+            },
+        },
+        Query::BorderColor(border_color) => card.border_color == *border_color,
+        Query::Frame(frame) => card.frame == *frame,
+        Query::FrameEffect(effect) => card.frame_effects.contains(effect),
+        Query::Game(GameParam::Game(game)) => card.games.contains(game),
+        Query::Time(TimeParam::Year(op, year)) => {
+            compare(*op, card.released_at.year() as i64, *year as i64)
+        },
+        Query::Time(TimeParam::Date(op, date)) => compare(*op, card.released_at, *date),
+        Query::SecurityStamp(stamp) => card.security_stamp.as_ref() == Some(stamp),
+        Query::Option(_) => true,
+        Query::Custom(term) => match name_term(term) {
+            Some(NameTerm::Contains(text)) => names(card).any(|name| contains(name, text)),
+            Some(NameTerm::Exact(text)) => names(card).any(|name| name.eq_ignore_ascii_case(text)),
+            None => false,
+        },
+        Query::Game(GameParam::InGame(_)) | Query::Time(TimeParam::Set(..)) | Query::Regex(_) => {
+            false
+        },
+    }
+}
+
+fn compare<T: PartialOrd>(op: ComparisonExpr, a: T, b: T) -> bool {
+    use ComparisonExpr::*;
+    match op {
+        AtLeast => a > b,
+        AtLeastInclusive => a >= b,
+        AtMost => a < b,
+        AtMostInclusive => a <= b,
+        Is => a == b,
+        IsNot => a != b,
+    }
+}
+
+/// Compares the colors of a card with the ones searched for, as sets. `c`
+/// and `m` search for colorless and multicolored cards.
+fn compare_colors(op: ComparisonExpr, card: &[Color], colors: Colors) -> bool {
+    use ComparisonExpr::*;
+    if colors.is_multicolored() {
+        let multicolored = card.len() > 1;
+        return match op {
+            Is | AtLeast | AtLeastInclusive => multicolored,
+            IsNot | AtMost | AtMostInclusive => !multicolored,
+        };
+    }
+    if colors.is_colorless() && op == AtLeastInclusive {
+        return card.is_empty();
+    }
+    let searched = ALL_COLORS.iter().filter(|&&color| colors.is(color)).count();
+    let common = card.iter().filter(|&&color| colors.is(color)).count();
+    let (subset, superset) = (common == card.len(), common == searched);
+    match op {
+        Is => subset && superset,
+        IsNot => !(subset && superset),
+        AtLeastInclusive => superset,
+        AtLeast => superset && card.len() > searched,
+        AtMostInclusive => subset,
+        AtMost => subset && card.len() < searched,
+    }
+}
+
+const ALL_COLORS: [Color; 5] = [
+    Color::White,
+    Color::Blue,
+    Color::Black,
+    Color::Red,
+    Color::Green,
+];
+
+/// Compares the colors of mana a card produces with the ones searched for.
+/// `c` searches for cards that produce colorless mana.
+fn produces(op: ComparisonExpr, card: &Card, colors: Colors) -> bool {
+    let produced = card.produced_mana.as_deref().unwrap_or_default();
+    if colors.is_colorless() {
+        let colorless = produced.iter().any(|mana| mana == "C");
+        return if op == ComparisonExpr::IsNot {
+            !colorless
+        } else {
+            colorless
+        };
+    }
+    let produced = ALL_COLORS
+        .iter()
+        .zip(["W", "U", "B", "R", "G"])
+        .filter(|(_, symbol)| produced.iter().any(|mana| mana == symbol))
+        .map(|(&color, _)| color)
+        .collect::<Vec<_>>();
+    compare_colors(op, &produced, colors)
+}
+
+/// The colors of a card, or of all its faces if the card doesn't have them.
+fn card_colors(card: &Card) -> Vec<Color> {
+    if !card.colors.is_empty() {
+        return card.colors.clone();
+    }
+    ALL_COLORS
+        .iter()
+        .copied()
+        .filter(|color| faces(card).iter().any(|face| face.colors.contains(color)))
+        .collect()
+}
+
+fn faces(card: &Card) -> &[CardFace] {
+    card.card_faces.as_deref().unwrap_or_default()
+}
+
+/// The texts of a field of the card, and of each of its faces, along with the
+/// name of the card or face they belong to.
+fn texts<'a>(
+    card: &'a Card,
+    card_text: Option<&'a str>,
+    face_text: impl Fn(&'a CardFace) -> Option<&'a str>,
+) -> impl Iterator<Item = (&'a str, &'a str)> {
+    let card_text = card_text.map(|text| (card.name.as_str(), text));
+    let face_texts = faces(card)
+        .iter()
+        .filter_map(move |face| face_text(face).map(|text| (face.name.as_str(), text)));
+    card_text.into_iter().chain(face_texts)
+}
+
+fn names(card: &Card) -> impl Iterator<Item = &str> {
+    std::iter::once(card.name.as_str()).chain(faces(card).iter().map(|face| face.name.as_str()))
+}
+
+fn contains(text: &str, searched: &str) -> bool {
+    text.to_lowercase().contains(&searched.to_lowercase())
+}
+
+fn type_line(card: &Card) -> Option<TypeLine> {
+    card.parse_type_line().ok().flatten()
+}
+
+/// The type line of the front face of the card.
+fn front_type_line(card: &Card) -> Option<TypeLineFace> {
+    type_line(card).and_then(|type_line| type_line.faces().first().cloned())
+}
+
+/// Checks if the front face of the card has the given type.
+fn front_has_type(card: &Card, card_type: &str) -> bool {
+    matches!(front_type_line(card), Some(face) if face.has_type(card_type))
+}
+
+fn mana_cost(card: &Card) -> Vec<ManaSymbol> {
+    let costs = match card.parse_mana_cost() {
+        Ok(Some(cost)) => vec![cost],
+        _ => faces(card)
+            .iter()
+            .filter_map(|face| face.parse_mana_cost().ok())
+            .collect(),
+    };
+    costs.into_iter().flat_map(ManaCost::into_symbols).collect()
+}
+
+fn boolean(param: &BooleanParam, card: &Card) -> bool {
+    use BooleanParam::*;
+    let oracle_texts = || {
+        texts(card, card.oracle_text.as_deref(), |f| {
+            f.oracle_text.as_deref()
+        })
+    };
+    let promo_type = |promo_type: &str| card.promo_types.iter().any(|t| t == promo_type);
+    match param {
+        ColorIndicator => {
+            card.color_indicator.is_some()
+                || faces(card)
+                    .iter()
+                    .any(|face| face.color_indicator.is_some())
+        },
+        WaterMark => {
+            card.watermark.is_some() || faces(card).iter().any(|face| face.watermark.is_some())
+        },
+        IsPhyrexian => mana_cost(card).iter().any(|symbol| {
+            matches!(
+                symbol,
+                ManaSymbol::Phyrexian(_) | ManaSymbol::HybridPhyrexian(..)
+            )
+        }),
+        IsHybrid => mana_cost(card).iter().any(|symbol| {
+            matches!(
+                symbol,
+                ManaSymbol::Hybrid(..)
+                    | ManaSymbol::TwoHybrid(_)
+                    | ManaSymbol::ColorlessHybrid(_)
+                    | ManaSymbol::HybridPhyrexian(..)
+            )
+        }),
+        IsSplit => card.layout == Layout::Split,
+        IsFlip => card.layout == Layout::Flip,
+        IsTransform => card.layout == Layout::Transform,
+        IsMeld => card.layout == Layout::Meld,
+        IsLeveler => card.layout == Layout::Leveler,
+        IsSpell => {
+            !matches!(
+                card.layout,
+                Layout::Token
+                    | Layout::DoubleFacedToken
+                    | Layout::Emblem
+                    | Layout::Planar
+                    | Layout::Scheme
+                    | Layout::Vanguard
+                    | Layout::ArtSeries
+            ) && type_line(card).is_some()
+                && !front_has_type(card, "Land")
+        },
+        IsPermanent => [
+            "Artifact",
+            "Battle",
+            "Creature",
+            "Enchantment",
+            "Land",
+            "Planeswalker",
+        ]
+        .iter()
+        .any(|card_type| front_has_type(card, card_type)),
+        IsHistoric => matches!(
+            type_line(card),
+            Some(type_line) if type_line.is_legendary()
+                || type_line.is_artifact()
+                || type_line.has_subtype("Saga")
+        ),
+        IsModal => oracle_texts().any(|(_, text)| {
+            text.lines()
+                .any(|line| line.starts_with("Choose ") && line.contains('—'))
+        }),
+        IsVanilla => {
+            front_has_type(card, "Creature")
+                && faces(card).is_empty()
+                && card.oracle_text.as_deref().unwrap_or_default().is_empty()
+        },
+        IsFunny => {
+            card.set_type == Some(SetType::Funny) || card.border_color == BorderColor::Silver
+        },
+        IsCommander => {
+            let legendary_creature = matches!(
+                front_type_line(card),
+                Some(face) if face.has_supertype("Legendary") && face.has_type("Creature")
+            );
+            legendary_creature
+                || oracle_texts().any(|(_, text)| text.contains("can be your commander"))
+        },
+        IsReserved => card.reserved,
+        IsFull => card.full_art,
+        IsNonFoil => card.nonfoil,
+        IsFoil => card.foil,
+        IsHires => card.highres_image,
+        IsDigital => card.digital,
+        IsPromo => card.promo,
+        IsSpotlight => card.story_spotlight,
+        IsReprint => card.reprint,
+        SoldInBoosters => card.booster,
+        SoldInPwDecks => promo_type("planeswalkerdeck"),
+        SoldInLeague => promo_type("league"),
+        SoldInBuyABox => promo_type("buyabox"),
+        SoldInGiftBox => promo_type("giftbox"),
+        SoldInIntroPack => promo_type("intropack"),
+        SoldInGameDay => promo_type("gameday"),
+        SoldInPreRelease => promo_type("prerelease"),
+        SoldInRelease => promo_type("release"),
+        IsDfc => matches!(
+            card.layout,
+            Layout::Transform | Layout::ModalDfc | Layout::Meld | Layout::DoubleFacedToken
+        ),
+        IsMdfc => card.layout == Layout::ModalDfc,
+        IsAdventure => card.layout == Layout::Adventure,
+        IsEtched => card.finishes.contains(&Finish::Etched),
+        IsGlossy => promo_type("glossy"),
+        IsTextless => card.textless,
+        IsGameChanger => card.game_changer,
+        IsOversized => card.oversized,
+        NewRarity | NewArt | NewArtist | NewFlavor | NewFrame | NewLanguage | IsUnique => false,
+    }
+}
+
+/// The value of a power, toughness or loyalty, counting `*` and `X` as 0.
+fn stat(value: &str) -> Option<f32> {
+    value
+        .split('+')
+        .map(|part| match part.trim() {
+            "*" | "X" | "?" | "" => Some(0.0),
+            part => part.parse::<f32>().ok(),
+        })
+        .sum()
+}
+
+fn compare_stats(op: ComparisonExpr, card: &Card, stat_index: usize, value: &str) -> bool {
+    let other = match value.to_ascii_lowercase().as_str() {
+        "pow" | "power" => Some(0),
+        "tou" | "toughness" => Some(1),
+        "loy" | "loyalty" => Some(2),
+        _ => None,
+    };
+    stat_holders(card)
+        .filter_map(|holder| {
+            let own = stat(holder[stat_index]?)?;
+            let searched = match other {
+                Some(other) => stat(holder[other]?)?,
+                None => stat(value)?,
+            };
+            Some(compare(op, own, searched))
+        })
+        .any(|matched| matched)
+}
+
+/// The power, toughness and loyalty of the card and of each of its faces.
+fn stat_holders(card: &Card) -> impl Iterator<Item = [Option<&str>; 3]> {
+    fn stats<'a>(
+        power: &'a Option<String>,
+        toughness: &'a Option<String>,
+        loyalty: &'a Option<String>,
+    ) -> [Option<&'a str>; 3] {
+        [power.as_deref(), toughness.as_deref(), loyalty.as_deref()]
+    }
+    std::iter::once(stats(&card.power, &card.toughness, &card.loyalty)).chain(
+        faces(card)
+            .iter()
+            .map(|face| stats(&face.power, &face.toughness, &face.loyalty)),
+    )
+}
+
+/// Parses a mana cost that can also be written without braces, like `2rr`.
+fn parse_cost(searched: &str) -> Option<Vec<ManaSymbol>> {
+    let searched = if searched.contains('{') {
+        searched.to_string()
+    } else {
+        let mut braced = String::new();
+        let mut generic = String::new();
+        for c in searched.chars() {
+            if c.is_ascii_digit() {
+                generic.push(c);
+                continue;
+            }
+            if !generic.is_empty() {
+                braced += &format!("{{{}}}", generic);
+                generic.clear();
+            }
+            braced += &format!("{{{}}}", c);
+        }
+        if !generic.is_empty() {
+            braced += &format!("{{{}}}", generic);
+        }
+        braced
+    };
+    searched
+        .parse::<ManaCost>()
+        .ok()
+        .map(ManaCost::into_symbols)
+}
+
+/// Checks if the card's cost has at least the symbols of `searched`.
+fn has_mana_cost(card: &Card, searched: &str) -> bool {
+    let searched = match parse_cost(searched) {
+        Some(symbols) => symbols,
+        None => return false,
+    };
+    let mut cost = mana_cost(card);
+    let generic = |symbols: &[ManaSymbol]| {
+        symbols
+            .iter()
+            .map(|symbol| match symbol {
+                ManaSymbol::Generic(amount) => *amount,
+                _ => 0,
+            })
+            .sum::<u32>()
+    };
+    if generic(&searched) > generic(&cost) {
+        return false;
+    }
+    for symbol in searched {
+        if let ManaSymbol::Generic(_) = symbol {
+            continue;
+        }
+        match cost.iter().position(|s| *s == symbol) {
+            Some(i) => {
+                cost.remove(i);
+            },
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Compares the devotion of the card's cost to the colors of the symbols in
+/// `searched` with how many symbols there are.
+fn compare_devotion(op: ComparisonExpr, card: &Card, searched: &str) -> bool {
+    let searched = match parse_cost(searched) {
+        Some(symbols) if !symbols.is_empty() => symbols,
+        _ => return false,
+    };
+    let colors = searched
+        .iter()
+        .flat_map(ManaSymbol::colors)
+        .collect::<Vec<_>>();
+    let devotion = mana_cost(card)
+        .iter()
+        .filter(|symbol| colors.iter().any(|&color| symbol.is(color)))
+        .count();
+    compare(op, devotion, searched.len())
+}
+
+/// Compares collector numbers by their leading digits, or as text when
+/// checking for equality.
+fn compare_collector_numbers(op: ComparisonExpr, card: &str, searched: &str) -> bool {
+    use ComparisonExpr::*;
+    let digits = |number: &str| {
+        let end = number
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(number.len());
+        number[..end].parse::<usize>().ok()
+    };
+    match op {
+        Is => card.eq_ignore_ascii_case(searched),
+        IsNot => !card.eq_ignore_ascii_case(searched),
+        _ => match (digits(card), digits(searched)) {
+            (Some(card), Some(searched)) => compare(op, card, searched),
+            _ => false,
+        },
+    }
+}
+
+fn string(param: &StringParam, card: &Card) -> bool {
+    use StringParam::*;
+    match param {
+        ManaCost(cost) => has_mana_cost(card, cost),
+        Type(text) => texts(card, card.type_line.as_deref(), |f| f.type_line.as_deref())
+            .any(|(_, type_line)| contains(type_line, text)),
+        Oracle(text) | OracleFull(text) => texts(card, card.oracle_text.as_deref(), |f| {
+            f.oracle_text.as_deref()
+        })
+        .any(|(name, oracle_text)| contains(oracle_text, &text.replace('~', name))),
+        Power(op, value) => compare_stats(*op, card, 0, value),
+        Toughness(op, value) => compare_stats(*op, card, 1, value),
+        Loyalty(op, value) => compare_stats(*op, card, 2, value),
+        Set(code) => card.set.eq_ignore_ascii_case(code.get()),
+        Artist(text) => texts(card, card.artist.as_deref(), |f| f.artist.as_deref())
+            .any(|(_, artist)| contains(artist, text)),
+        Flavor(text) => texts(card, card.flavor_text.as_deref(), |f| {
+            f.flavor_text.as_deref()
+        })
+        .any(|(_, flavor_text)| contains(flavor_text, text)),
+        WaterMark(text) => texts(card, card.watermark.as_deref(), |f| f.watermark.as_deref())
+            .any(|(_, watermark)| watermark.eq_ignore_ascii_case(text)),
+        Lang(lang) => card.lang.to_string().eq_ignore_ascii_case(lang),
+        LangAny => true,
+        Name(text) => names(card).any(|name| contains(name, text)),
+        ExactName(text) => names(card).any(|name| name.eq_ignore_ascii_case(text)),
+        Keyword(keyword) => card
+            .keywords
+            .iter()
+            .any(|k| k.eq_ignore_ascii_case(keyword)),
+        Devotion(op, value) => compare_devotion(*op, card, value),
+        CollectorNumber(op, number) => {
+            compare_collector_numbers(*op, &card.collector_number, number)
+        },
+        Block(_) | WasInSet(_) | InCube(_) | PrintedInLang(_) | ArtTag(_) | OracleTag(_) => false,
+    }
+}
+
+/// The lowest price of the card in a currency, over all its finishes.
+fn lowest_price(prices: &[Option<Decimal>]) -> Option<Decimal> {
+    prices.iter().flatten().min().copied()
+}
+
+fn numeric(param: &NumericParam, card: &Card) -> bool {
+    use NumericParam::*;
+    let price = |op, price, value: &Decimal| match price {
+        Some(price) => compare(op, price, *value),
+        None => false,
+    };
+    let Price {
+        usd,
+        usd_foil,
+        usd_etched,
+        eur,
+        eur_foil,
+        tix,
+    } = card.prices;
+    match param {
+        Cmc(op, value) => compare(*op, card.cmc, *value as f32),
+        CollectorNumber(number) => card.collector_number == number.to_string(),
+        UsdPrice(op, value) => price(*op, lowest_price(&[usd, usd_foil, usd_etched]), value),
+        EurPrice(op, value) => price(*op, lowest_price(&[eur, eur_foil]), value),
+        TixPrice(op, value) => price(*op, tix, value),
+        PowTou(op, value) => stat_holders(card).any(|[power, toughness, _]| {
+            match (power.and_then(stat), toughness.and_then(stat)) {
+                (Some(power), Some(toughness)) => compare(*op, power + toughness, *value as f32),
+                _ => false,
+            }
+        }),
+        ArenaId(id) => card.arena_id == Some(*id),
+        MtgoId(id) => card.mtgo_id == Some(*id) || card.mtgo_foil_id == Some(*id),
+        MultiverseId(id) => matches!(&card.multiverse_ids, Some(ids) if ids.contains(id)),
+        Prints(..) | Sets(..) | PaperPrints(..) | PaperSets(..) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card_searcher::ComparisonExpr::*;
+
+    const LIGHTNING_BOLT: &str = include_str!("../../tests/fixtures/lightning_bolt.json");
+
+    fn card(json: &str) -> Card {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn matches(query: &str, card: &Card) -> bool {
+        Query::parse(query).unwrap().matches(card).unwrap()
+    }
+
+    #[test]
+    fn evaluate_queries() {
+        let bolt = card(LIGHTNING_BOLT);
+        let delver = card(include_str!("../../tests/fixtures/layouts/transform.json"));
+        let bonecrusher = card(include_str!("../../tests/fixtures/layouts/adventure.json"));
+
+        for query in &[
+            "t:instant o:\"~ deals 3 damage\" c:r id<=rg cmc=1 r:u",
+            "lightning -t:creature (legal:modern or banned:modern)",
+            "!\"lightning bolt\" m:{R} m:r -m:rr (pow<1 or usd<=3)",
+            "c=r -c:m -c:c id!=rg s:a25 lang:en year>=2018 date<2030-01-01",
+            "border:black frame:2015 game:paper is:reprint is:spell -is:permanent is:nonfoil",
+        ] {
+            assert!(matches(query, &bolt), "{}", query);
+        }
+        for query in &[
+            "t:creature",
+            "o:\"deals 2\"",
+            "c>r",
+            "r>u",
+            "usd<2",
+            "-legal:vintage",
+        ] {
+            assert!(!matches(query, &bolt), "{}", query);
+        }
+
+        assert!(matches(
+            "t:insect pow=3 tou<pow o:flying is:transform",
+            &delver
+        ));
+        assert!(matches("t:wizard is:permanent c:u -is:vanilla", &delver));
+        assert!(!matches("t:instant", &delver));
+        assert!(matches(
+            "t:adventure o:\"~ deals 2 damage\" pow>tou",
+            &bonecrusher
+        ));
+        assert!(matches("!stomp", &bonecrusher));
+    }
+
+    #[test]
+    fn evaluate_new_params() {
+        let bolt = card(LIGHTNING_BOLT);
+        let delver = card(include_str!("../../tests/fixtures/layouts/transform.json"));
+        let bonecrusher = card(include_str!("../../tests/fixtures/layouts/adventure.json"));
+        let valakut = card(include_str!("../../tests/fixtures/layouts/modal_dfc.json"));
+
+        for query in &[
+            "name:bolt !\"lightning bolt\" -!lightning -keyword:flying",
+            "mtgoid:67197 mtgoid:67196 multiverseid:442130 -arenaid:67196",
+            "cn>=100 cn<200 cn=141 -cn:141a usd>2.1 usd<=2.14 eur<1.9 tix=0.04",
+            "devotion:r devotion=r -devotion:rr -devotion:g -produces:r -is:dfc -is:etched",
+            "order:cmc direction:desc prefer:oldest display:text include:extras",
+        ] {
+            assert!(matches(query, &bolt), "{}", query);
+        }
+        assert!(matches(
+            "is:dfc is:transform -is:mdfc keyword:flying pt=2 pt=5 -pt>5",
+            &delver
+        ));
+        assert!(matches(
+            "is:adventure -is:dfc devotion:rr devotion<rrr pt=7 name:stomp",
+            &bonecrusher
+        ));
+        assert!(matches(
+            "is:mdfc is:dfc produces:r produces=r -produces:g -produces:c",
+            &valakut
+        ));
+    }
+
+    #[test]
+    fn report_unsupported_params() {
+        let bolt = card(LIGHTNING_BOLT);
+        let query =
+            Query::parse("t:instant (prints>1 or -new:art) o:/damage$/ in:lea art:fire").unwrap();
+        assert_eq!(
+            query
+                .unsupported()
+                .iter()
+                .map(|query| query.to_param())
+                .collect::<Vec<_>>(),
+            ["prints>1", "new:art", "o:/damage$/", "in:lea", "art:fire"]
+        );
+        match query.matches(&bolt) {
+            Err(Error::UnsupportedQuery(params)) => assert_eq!(params.len(), 5),
+            result => panic!("expected unsupported params, got {:?}", result),
+        }
+
+        let mut search = SearchBuilder::new();
+        search
+            .param(StringParam::Type("instant".into()))
+            .param(NumericParam::Cmc(AtMostInclusive, 1))
+            .param(crate::card_searcher::not(BooleanParam::IsFunny));
+        assert!(search.matches(&bolt).unwrap());
+        assert!(NumericParam::Cmc(AtMost, 1).matches(&bolt).is_ok());
+        assert!(!NumericParam::Cmc(AtMost, 1).matches(&bolt).unwrap());
+        assert!(BooleanParam::IsUnique.matches(&bolt).is_err());
+    }
+}
@@ -0,0 +1,927 @@
+//! A parser from Scryfall's [search syntax](https://scryfall.com/docs/syntax)
+//! into a [`Query`].
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use serde::de::value::Error as ValueError;
+use serde::de::{DeserializeOwned, IntoDeserializer};
+
+use crate::card::{
+    BorderColor, Color, Colors, Decimal, Frame, FrameEffect, Game, Rarity, SecurityStamp,
+};
+use crate::card_searcher::{
+    BooleanParam, ColorParam, ComparisonExpr, DisplayMode, FormatParam, GameParam, NumericParam,
+    OptionParam, Param, PreferStrategy, Query, RarityParam, RegexParam, SortDirection, SortMethod,
+    StringParam, TimeParam, UniqueStrategy,
+};
+use crate::error::Error;
+use crate::format::Format;
+use crate::set::SetCode;
+
+/// Every [`BooleanParam`], to find the one a keyword refers to.
+const BOOLEAN_PARAMS: [BooleanParam; 49] = {
+    use BooleanParam::*;
+    [
+        ColorIndicator,
+        WaterMark,
+        NewRarity,
+        NewArt,
+        NewArtist,
+        NewFlavor,
+        NewFrame,
+        NewLanguage,
+        IsPhyrexian,
+        IsHybrid,
+        IsSplit,
+        IsFlip,
+        IsTransform,
+        IsMeld,
+        IsLeveler,
+        IsSpell,
+        IsPermanent,
+        IsHistoric,
+        IsModal,
+        IsVanilla,
+        IsFunny,
+        IsCommander,
+        IsReserved,
+        IsFull,
+        IsNonFoil,
+        IsFoil,
+        IsHires,
+        IsDigital,
+        IsPromo,
+        IsSpotlight,
+        IsUnique,
+        IsReprint,
+        SoldInBoosters,
+        SoldInPwDecks,
+        SoldInLeague,
+        SoldInBuyABox,
+        SoldInGiftBox,
+        SoldInIntroPack,
+        SoldInGameDay,
+        SoldInPreRelease,
+        SoldInRelease,
+        IsDfc,
+        IsMdfc,
+        IsAdventure,
+        IsEtched,
+        IsGlossy,
+        IsTextless,
+        IsGameChanger,
+        IsOversized,
+    ]
+};
+
+/// Every [`SortMethod`], to find the one an `order:` refers to.
+const SORT_METHODS: [SortMethod; 13] = {
+    use SortMethod::*;
+    [
+        Name, Set, Released, Rarity, Color, Usd, Tix, Eur, Cmc, Power, Toughness, Edhrec, Artist,
+    ]
+};
+
+/// Every [`PreferStrategy`], to find the one a `prefer:` refers to.
+const PREFER_STRATEGIES: [PreferStrategy; 12] = {
+    use PreferStrategy::*;
+    [
+        Oldest,
+        Newest,
+        UsdLow,
+        UsdHigh,
+        EurLow,
+        EurHigh,
+        TixLow,
+        TixHigh,
+        Promo,
+        Atypical,
+        UniversesBeyond,
+        NotUniversesBeyond,
+    ]
+};
+
+/// The language codes Scryfall accepts in `in:`, to tell them apart from set
+/// codes.
+const LANGUAGES: [&str; 18] = [
+    "en", "es", "fr", "de", "it", "pt", "ja", "ko", "ru", "zhs", "zht", "he", "la", "grc", "ar",
+    "sa", "ph", "qya",
+];
+
+/// How deep negations and parentheses can be nested, so that a query can't
+/// overflow the stack.
+const MAX_DEPTH: usize = 256;
+
+impl Query {
+    /// Parses a query written in Scryfall's
+    /// [search syntax](https://scryfall.com/docs/syntax).
+    ///
+    /// Terms with a keyword this module has a param for are parsed into that
+    /// param, and so are exact names (`!"Lightning Bolt"`) and regular
+    /// expressions (`o:/^{T}:/`). Everything else, like bare words or keywords
+    /// without a param, is kept as written in a [`Query::Custom`].
+    ///
+    /// Keywords that Scryfall compares differently depending on the operator
+    /// are normalized: `c:rg` becomes `c>=rg` and `id:rg` becomes `id<=rg`, as
+    /// Scryfall interprets them.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card_searcher::ComparisonExpr::AtMostInclusive;
+    /// use scryfall::card_searcher::NumericParam::Cmc;
+    /// use scryfall::card_searcher::StringParam::Type;
+    /// use scryfall::card_searcher::{Param, Query};
+    ///
+    /// let query = Query::parse("(t:goblin OR t:elf) cmc<=2 -\"goblin king\"").unwrap();
+    /// assert_eq!(
+    ///     query,
+    ///     Query::And(vec![
+    ///         Query::from(Type("goblin".into())) | Type("elf".into()).into(),
+    ///         Cmc(AtMostInclusive, 2).into(),
+    ///         !Query::Custom("\"goblin king\"".into()),
+    ///     ])
+    /// );
+    /// assert_eq!(
+    ///     query.to_param(),
+    ///     "(t:goblin or t:elf) cmc<=2 -\"goblin king\""
+    /// );
+    /// ```
+    pub fn parse(query: &str) -> crate::Result<Query> {
+        let mut parser = Parser {
+            query,
+            pos: 0,
+            depth: 0,
+        };
+        let terms = parser.parse_or()?;
+        parser.skip_whitespace();
+        if parser.pos < query.len() {
+            return Err(parser.error("unmatched `)`"));
+        }
+        Ok(terms.unwrap_or_else(|| Query::And(Vec::new())))
+    }
+}
+
+impl FromStr for Query {
+    type Err = Error;
+
+    fn from_str(query: &str) -> crate::Result<Self> {
+        Query::parse(query)
+    }
+}
+
+/// The value of a `keyword:value` term.
+enum Value<'a> {
+    Bare(&'a str),
+    Quoted(String),
+    Regex(&'a str),
+}
+
+struct Parser<'a> {
+    query: &'a str,
+    pos: usize,
+    /// How many negations and parentheses the parser is inside of.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, reason: &str) -> Error {
+        Error::InvalidQuery {
+            query: self.query.to_string(),
+            reason: format!("{} at position {}", reason, self.pos),
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.query[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Takes characters until one matches `end`, returning them.
+    fn take_until(&mut self, end: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(end).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Takes a word, up to whitespace or a parenthesis.
+    fn take_word(&mut self) -> &'a str {
+        self.take_until(|c| c.is_whitespace() || c == '(' || c == ')')
+    }
+
+    /// Takes the text up to the next unescaped `delimiter`, after skipping the
+    /// opening one.
+    fn take_delimited(&mut self, delimiter: char) -> crate::Result<&'a str> {
+        self.pos += delimiter.len_utf8();
+        let rest = self.rest();
+        let mut escaped = false;
+        for (i, c) in rest.char_indices() {
+            if c == delimiter && !escaped {
+                self.pos += i + delimiter.len_utf8();
+                return Ok(&rest[..i]);
+            }
+            escaped = c == '\\' && !escaped;
+        }
+        Err(self.error(&format!("unterminated `{}`", delimiter)))
+    }
+
+    /// Checks if the word `word` is next, ignoring case.
+    fn at_word(&self, word: &str) -> bool {
+        let rest = self.rest();
+        rest.len() >= word.len()
+            && rest.is_char_boundary(word.len())
+            && rest[..word.len()].eq_ignore_ascii_case(word)
+            && !matches!(
+                rest[word.len()..].chars().next(),
+                Some(c) if !c.is_whitespace() && c != '('
+            )
+    }
+
+    /// Consumes the word `word`, ignoring case, if it's next.
+    fn eat_word(&mut self, word: &str) -> bool {
+        let found = self.at_word(word);
+        if found {
+            self.pos += word.len();
+        }
+        found
+    }
+
+    /// Parses alternatives separated by `or`. Returns `None` if there are no
+    /// terms.
+    fn parse_or(&mut self) -> crate::Result<Option<Query>> {
+        let mut alternatives = Vec::new();
+        loop {
+            match self.parse_and()? {
+                Some(query) => alternatives.push(query),
+                None if alternatives.is_empty() && !self.eat_word("or") => return Ok(None),
+                None => return Err(self.error("expected a term around `or`")),
+            }
+            self.skip_whitespace();
+            if !self.eat_word("or") {
+                break;
+            }
+        }
+        Ok(Some(if alternatives.len() == 1 {
+            alternatives.remove(0)
+        } else {
+            Query::Or(alternatives)
+        }))
+    }
+
+    /// Parses terms separated by whitespace or `and`, up to an `or`, a `)`
+    /// or the end. Returns `None` if there are no terms.
+    fn parse_and(&mut self) -> crate::Result<Option<Query>> {
+        let mut terms = Vec::new();
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None | Some(')') => break,
+                _ if self.eat_word("and") => continue,
+                _ if self.at_word("or") => break,
+                _ => terms.push(self.parse_unary()?),
+            }
+        }
+        Ok(match terms.len() {
+            0 => None,
+            1 => terms.pop(),
+            _ => Some(Query::And(terms)),
+        })
+    }
+
+    /// Parses a term, possibly negated, or a group between parentheses.
+    fn parse_unary(&mut self) -> crate::Result<Query> {
+        if !matches!(self.peek(), Some('-') | Some('(')) {
+            return self.parse_term();
+        }
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("too many nested negations or parentheses"));
+        }
+        self.depth += 1;
+        let query = self.parse_nested();
+        self.depth -= 1;
+        query
+    }
+
+    /// Parses a negated term or a group between parentheses.
+    fn parse_nested(&mut self) -> crate::Result<Query> {
+        match self.peek() {
+            Some('-') => {
+                self.pos += 1;
+                match self.peek() {
+                    Some(c) if !c.is_whitespace() && c != ')' => {
+                        Ok(Query::Not(Box::new(self.parse_unary()?)))
+                    },
+                    _ => Err(self.error("expected a term after `-`")),
+                }
+            },
+            Some('(') => {
+                self.pos += 1;
+                let query = self.parse_or()?;
+                self.skip_whitespace();
+                if self.peek() != Some(')') {
+                    return Err(self.error("unclosed `(`"));
+                }
+                self.pos += 1;
+                query.ok_or_else(|| self.error("empty parentheses"))
+            },
+            _ => unreachable!("only called on `-` or `(`"),
+        }
+    }
+
+    /// Parses a single term.
+    fn parse_term(&mut self) -> crate::Result<Query> {
+        let start = self.pos;
+        match self.peek() {
+            Some('!') => {
+                self.pos += 1;
+                let name = if self.peek() == Some('"') {
+                    Some(unescape(self.take_delimited('"')?))
+                } else {
+                    Some(self.take_word())
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string)
+                };
+                if let Some(name) = name {
+                    return Ok(Query::String(StringParam::ExactName(name)));
+                }
+            },
+            Some('"') => {
+                self.take_delimited('"')?;
+            },
+            _ => {
+                let keyword = self.take_until(|c| !(c.is_ascii_alphanumeric() || c == '_'));
+                let rest = self.rest();
+                let op = ["!=", "<=", ">=", ":", "=", "<", ">"]
+                    .iter()
+                    .find(|op| rest.starts_with(**op));
+                match op {
+                    Some(op) if !keyword.is_empty() => {
+                        self.pos += op.len();
+                        let value = match self.peek() {
+                            Some('"') => Value::Quoted(unescape(self.take_delimited('"')?)),
+                            Some('/') => Value::Regex(self.take_delimited('/')?),
+                            _ => match self.take_word() {
+                                "" => {
+                                    return Err(
+                                        self.error(&format!("missing value for `{}`", keyword))
+                                    )
+                                },
+                                value => Value::Bare(value),
+                            },
+                        };
+                        let keyword = keyword.to_ascii_lowercase();
+                        let term = match value {
+                            Value::Bare(value) => param(&keyword, op, value),
+                            Value::Quoted(value) => param(&keyword, op, &value),
+                            Value::Regex(regex) if *op == ":" => regex_param(&keyword, regex),
+                            Value::Regex(_) => None,
+                        };
+                        if let Some(term) = term {
+                            return Ok(term);
+                        }
+                    },
+                    _ => {
+                        self.take_word();
+                    },
+                }
+            },
+        }
+        Ok(Query::Custom(self.query[start..self.pos].to_string()))
+    }
+}
+
+/// Removes the backslashes that escape quotes and backslashes in a quoted
+/// value. Other backslashes are kept as written.
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some(&next)) if next == '\\' || next == '"' => {
+                unescaped.push(next);
+                chars.next();
+            },
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// The comparison of an operator. `:` means `colon` for the keyword.
+fn comparison(op: &str, colon: ComparisonExpr) -> ComparisonExpr {
+    use ComparisonExpr::*;
+    match op {
+        "!=" => IsNot,
+        "<=" => AtMostInclusive,
+        ">=" => AtLeastInclusive,
+        "=" => Is,
+        "<" => AtMost,
+        ">" => AtLeast,
+        _ => colon,
+    }
+}
+
+/// Deserializes an enum from the name Scryfall uses for it.
+fn from_name<T: DeserializeOwned>(name: &str) -> Option<T> {
+    T::deserialize(IntoDeserializer::<ValueError>::into_deserializer(name)).ok()
+}
+
+fn colors(value: &str) -> Option<Colors> {
+    let value = value.to_ascii_lowercase();
+    let color = |name: &str| {
+        Some(match name {
+            "white" => Color::White,
+            "blue" => Color::Blue,
+            "black" => Color::Black,
+            "red" => Color::Red,
+            "green" => Color::Green,
+            _ => return None,
+        })
+    };
+    match value.as_str() {
+        "c" | "colorless" => return Some(Colors::colorless()),
+        "m" | "multicolor" => return Some(Colors::multicolored()),
+        name => {
+            if let Some(color) = color(name) {
+                return Some(Colors::from(&[color][..]));
+            }
+        },
+    }
+    let mut colors = Vec::new();
+    for letter in value.chars() {
+        let color = match letter {
+            'w' => Color::White,
+            'u' => Color::Blue,
+            'b' => Color::Black,
+            'r' => Color::Red,
+            'g' => Color::Green,
+            _ => return None,
+        };
+        if !colors.contains(&color) {
+            colors.push(color);
+        }
+    }
+    Some(Colors::from(&colors[..]))
+}
+
+fn rarity(value: &str) -> Rarity {
+    match value.to_ascii_lowercase().as_str() {
+        "c" | "common" => Rarity::Common,
+        "u" | "uncommon" => Rarity::Uncommon,
+        "r" | "rare" => Rarity::Rare,
+        "s" | "special" => Rarity::Special,
+        "m" | "mythic" => Rarity::Mythic,
+        "b" | "bonus" => Rarity::Bonus,
+        _ => Rarity::Other(value.to_string()),
+    }
+}
+
+/// The typed param for a regular expression on `keyword`.
+fn regex_param(keyword: &str, regex: &str) -> Option<Query> {
+    let param = match keyword {
+        "name" => RegexParam::Name,
+        "t" | "type" => RegexParam::Type,
+        "o" | "oracle" => RegexParam::Oracle,
+        "fo" | "fulloracle" => RegexParam::OracleFull,
+        "ft" | "flavor" => RegexParam::Flavor,
+        _ => return None,
+    };
+    Some(Query::Regex(param(regex.to_string())))
+}
+
+/// The inline option for `keyword`.
+fn option_param(keyword: &str, value: &str) -> Option<OptionParam> {
+    let value = value.to_ascii_lowercase();
+    Some(match keyword {
+        "order" => OptionParam::Order(SORT_METHODS.iter().copied().find(|m| m.name() == value)?),
+        "direction" => {
+            use SortDirection::*;
+            let directions = [Auto, Ascending, Descending];
+            OptionParam::Direction(directions.iter().copied().find(|d| d.name() == value)?)
+        },
+        "unique" => {
+            use UniqueStrategy::*;
+            let strategies = [Cards, Arts, Prints];
+            OptionParam::Unique(strategies.iter().copied().find(|s| s.name() == value)?)
+        },
+        "prefer" => OptionParam::Prefer(
+            PREFER_STRATEGIES
+                .iter()
+                .copied()
+                .find(|s| s.name() == value)?,
+        ),
+        "display" => {
+            use DisplayMode::*;
+            let modes = [Grid, Checklist, Full, Text];
+            OptionParam::Display(modes.iter().copied().find(|m| m.name() == value)?)
+        },
+        "include" if value == "extras" => OptionParam::IncludeExtras,
+        _ => return None,
+    })
+}
+
+/// The typed param for `keyword`, or `None` if there isn't one that
+/// represents the term exactly.
+fn param(keyword: &str, op: &str, value: &str) -> Option<Query> {
+    use ComparisonExpr::*;
+    let colon = op == ":";
+    let string = |param: fn(String) -> StringParam| {
+        if colon {
+            Some(Query::String(param(value.to_string())))
+        } else {
+            None
+        }
+    };
+    let number = |param: fn(ComparisonExpr, usize) -> NumericParam| {
+        Some(Query::Numeric(param(
+            comparison(op, Is),
+            value.parse().ok()?,
+        )))
+    };
+    let price = |param: fn(ComparisonExpr, Decimal) -> NumericParam| {
+        Some(Query::Numeric(param(
+            comparison(op, Is),
+            value.parse().ok()?,
+        )))
+    };
+    let id = |param: fn(usize) -> NumericParam| {
+        if colon {
+            Some(Query::Numeric(param(value.parse().ok()?)))
+        } else {
+            None
+        }
+    };
+    let set = |param: fn(SetCode) -> StringParam| {
+        if colon {
+            Some(Query::String(param(SetCode::try_from(value).ok()?)))
+        } else {
+            None
+        }
+    };
+    let format = |param: fn(Format) -> FormatParam| {
+        if colon {
+            Some(Query::Format(param(Format::from_str(value).ok()?)))
+        } else {
+            None
+        }
+    };
+    match keyword {
+        "is" | "has" | "new" if colon => {
+            let term = format!("{}:{}", keyword, value.to_ascii_lowercase());
+            BOOLEAN_PARAMS
+                .iter()
+                .find(|param| param.to_param() == term)
+                .map(|param| Query::Boolean(*param))
+        },
+        "m" | "mana" => string(StringParam::ManaCost),
+        "t" | "type" => string(StringParam::Type),
+        "o" | "oracle" => string(StringParam::Oracle),
+        "fo" | "fulloracle" => string(StringParam::OracleFull),
+        "pow" | "power" => Some(Query::String(StringParam::Power(
+            comparison(op, Is),
+            value.to_string(),
+        ))),
+        "tou" | "toughness" => Some(Query::String(StringParam::Toughness(
+            comparison(op, Is),
+            value.to_string(),
+        ))),
+        "loy" | "loyalty" => Some(Query::String(StringParam::Loyalty(
+            comparison(op, Is),
+            value.to_string(),
+        ))),
+        "s" | "set" | "e" | "edition" => set(StringParam::Set),
+        "b" | "block" => set(StringParam::Block),
+        "in" if colon => {
+            let lowercase = value.to_ascii_lowercase();
+            if LANGUAGES.contains(&lowercase.as_str()) {
+                Some(Query::String(StringParam::PrintedInLang(value.to_string())))
+            } else {
+                match from_name::<Game>(&lowercase) {
+                    Some(Game::Other(_)) | None => set(StringParam::WasInSet),
+                    Some(game) => Some(Query::Game(GameParam::InGame(game))),
+                }
+            }
+        },
+        "cube" => string(StringParam::InCube),
+        "a" | "artist" => string(StringParam::Artist),
+        "ft" | "flavor" => string(StringParam::Flavor),
+        "wt" | "watermark" => string(StringParam::WaterMark),
+        "lang" | "language" if colon && value.eq_ignore_ascii_case("any") => {
+            Some(Query::String(StringParam::LangAny))
+        },
+        "lang" | "language" => string(StringParam::Lang),
+        "cmc" | "mv" | "manavalue" => number(NumericParam::Cmc),
+        "cn" | "number" => match value.parse() {
+            Ok(number) if colon => Some(Query::Numeric(NumericParam::CollectorNumber(number))),
+            _ => Some(Query::String(StringParam::CollectorNumber(
+                comparison(op, Is),
+                value.to_string(),
+            ))),
+        },
+        "tix" => price(NumericParam::TixPrice),
+        "eur" => price(NumericParam::EurPrice),
+        "usd" => price(NumericParam::UsdPrice),
+        "pt" | "powtou" => number(NumericParam::PowTou),
+        "arenaid" => id(NumericParam::ArenaId),
+        "mtgoid" => id(NumericParam::MtgoId),
+        "multiverseid" => id(NumericParam::MultiverseId),
+        "name" => string(StringParam::Name),
+        "keyword" => string(StringParam::Keyword),
+        "art" | "atag" | "arttag" => string(StringParam::ArtTag),
+        "function" | "otag" | "oracletag" => string(StringParam::OracleTag),
+        "devotion" => Some(Query::String(StringParam::Devotion(
+            comparison(op, AtLeastInclusive),
+            value.to_string(),
+        ))),
+        "produces" => Some(Query::Color(ColorParam::Produces(
+            comparison(op, AtLeastInclusive),
+            colors(value)?,
+        ))),
+        "stamp" if colon => from_name::<SecurityStamp>(value).map(Query::SecurityStamp),
+        "order" | "direction" | "unique" | "prefer" | "display" | "include" if colon => {
+            option_param(keyword, value).map(Query::Option)
+        },
+        "prints" => number(NumericParam::Prints),
+        "sets" => number(NumericParam::Sets),
+        "paperprints" => number(NumericParam::PaperPrints),
+        "papersets" => number(NumericParam::PaperSets),
+        "r" | "rarity" => Some(Query::Rarity(RarityParam(
+            comparison(op, Is),
+            rarity(value),
+        ))),
+        "c" | "color" | "colors" => Some(Query::Color(ColorParam::Color(
+            comparison(op, AtLeastInclusive),
+            colors(value)?,
+        ))),
+        "id" | "identity" | "ci" => Some(Query::Color(ColorParam::ColorIdentity(
+            comparison(op, AtMostInclusive),
+            colors(value)?,
+        ))),
+        "f" | "format" | "legal" => format(FormatParam::Legal),
+        "banned" => format(FormatParam::Banned),
+        "restricted" => format(FormatParam::Restricted),
+        "border" if colon => from_name::<BorderColor>(value).map(Query::BorderColor),
+        "frame" if colon => match (from_name::<Frame>(value)?, from_name::<FrameEffect>(value)?) {
+            (Frame::Other(_), FrameEffect::Other(_)) => None,
+            (Frame::Other(_), effect) => Some(Query::FrameEffect(effect)),
+            (frame, _) => Some(Query::Frame(frame)),
+        },
+        "game" if colon => from_name::<Game>(value).map(|game| Query::Game(GameParam::Game(game))),
+        "year" => Some(Query::Time(TimeParam::Year(
+            comparison(op, Is),
+            value.parse().ok()?,
+        ))),
+        "date" => Some(Query::Time(
+            match NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+                Ok(date) => TimeParam::Date(comparison(op, Is), date),
+                Err(_) => TimeParam::Set(comparison(op, Is), SetCode::try_from(value).ok()?),
+            },
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(query: &str) -> Query {
+        Query::parse(query).unwrap()
+    }
+
+    #[test]
+    fn params_round_trip() {
+        use ComparisonExpr::*;
+        let set = |code| SetCode::try_from(code).unwrap();
+        let colors = |colors: &[Color]| Colors::from(colors);
+        let mut queries = BOOLEAN_PARAMS
+            .iter()
+            .map(|&param| Query::from(param))
+            .collect::<Vec<_>>();
+        queries.extend(vec![
+            StringParam::ManaCost("{2}{R}".into()).into(),
+            StringParam::Type("goblin".into()).into(),
+            StringParam::Type("time lord".into()).into(),
+            StringParam::Oracle("draw a card".into()).into(),
+            StringParam::OracleFull("(This".into()).into(),
+            StringParam::Oracle("a\\".into()).into(),
+            StringParam::Oracle("say \"hi\" \\o/".into()).into(),
+            StringParam::Artist("Jim \"Bo\" Neil".into()).into(),
+            StringParam::Flavor("\"".into()).into(),
+            StringParam::Type("/".into()).into(),
+            StringParam::ManaCost("{2} {R}".into()).into(),
+            StringParam::ExactName("\"Ach! Hans, Run!\"".into()).into(),
+            StringParam::ExactName(String::new()).into(),
+            StringParam::Power(AtLeast, "tou".into()).into(),
+            StringParam::Toughness(IsNot, "*".into()).into(),
+            StringParam::Loyalty(AtMostInclusive, "3".into()).into(),
+            StringParam::Set(set("war")).into(),
+            StringParam::Block(set("ktk")).into(),
+            StringParam::WasInSet(set("lea")).into(),
+            StringParam::InCube("vintage".into()).into(),
+            StringParam::Artist("Christopher Moeller".into()).into(),
+            StringParam::Flavor("Mishra".into()).into(),
+            StringParam::WaterMark("orzhov".into()).into(),
+            StringParam::Lang("ja".into()).into(),
+            StringParam::LangAny.into(),
+            StringParam::PrintedInLang("ru".into()).into(),
+            NumericParam::Cmc(AtMost, 3).into(),
+            NumericParam::CollectorNumber(123).into(),
+            NumericParam::TixPrice(AtLeastInclusive, Decimal::new(5, 2)).into(),
+            NumericParam::EurPrice(Is, Decimal::from(2)).into(),
+            NumericParam::UsdPrice(AtMostInclusive, Decimal::new(250, 2)).into(),
+            NumericParam::Prints(AtLeast, 10).into(),
+            NumericParam::Sets(Is, 1).into(),
+            NumericParam::PaperPrints(IsNot, 1).into(),
+            NumericParam::PaperSets(AtLeast, 2).into(),
+            NumericParam::PowTou(AtLeastInclusive, 10).into(),
+            NumericParam::ArenaId(67330).into(),
+            NumericParam::MtgoId(81940).into(),
+            NumericParam::MultiverseId(442130).into(),
+            StringParam::Name("goblin king".into()).into(),
+            StringParam::ExactName("Lightning Bolt".into()).into(),
+            StringParam::ExactName("Fire".into()).into(),
+            StringParam::Keyword("flying".into()).into(),
+            StringParam::Devotion(AtLeastInclusive, "{u/b}{u/b}".into()).into(),
+            StringParam::CollectorNumber(AtMost, "100".into()).into(),
+            StringParam::CollectorNumber(Is, "12a".into()).into(),
+            StringParam::ArtTag("squirrel".into()).into(),
+            StringParam::OracleTag("removal".into()).into(),
+            ColorParam::Produces(Is, colors(&[Color::Blue, Color::Black])).into(),
+            SecurityStamp::Triangle.into(),
+            RegexParam::Name("^goblin".into()).into(),
+            RegexParam::Type("god$".into()).into(),
+            RegexParam::Oracle("^{T}:".into()).into(),
+            RegexParam::OracleFull("\\(.*\\)".into()).into(),
+            RegexParam::Flavor("\\/".into()).into(),
+            OptionParam::Order(SortMethod::Released).into(),
+            OptionParam::Direction(SortDirection::Descending).into(),
+            OptionParam::Unique(UniqueStrategy::Arts).into(),
+            OptionParam::Prefer(PreferStrategy::NotUniversesBeyond).into(),
+            OptionParam::Display(DisplayMode::Checklist).into(),
+            OptionParam::IncludeExtras.into(),
+            RarityParam(AtLeastInclusive, Rarity::Rare).into(),
+            RarityParam(Is, Rarity::Special).into(),
+            ColorParam::Color(Is, colors(&[Color::White, Color::Blue])).into(),
+            ColorParam::Color(AtLeastInclusive, Colors::multicolored()).into(),
+            ColorParam::ColorIdentity(AtMostInclusive, colors(&[Color::Red])).into(),
+            ColorParam::ColorIdentity(Is, Colors::colorless()).into(),
+            FormatParam::Legal(Format::Modern).into(),
+            FormatParam::Banned(Format::Commander).into(),
+            FormatParam::Restricted(Format::Other("tinyleaders".into())).into(),
+            BorderColor::Borderless.into(),
+            Frame::Y2015.into(),
+            FrameEffect::ExtendedArt.into(),
+            GameParam::Game(Game::Arena).into(),
+            GameParam::InGame(Game::Mtgo).into(),
+            TimeParam::Year(AtMostInclusive, 1995).into(),
+            TimeParam::Date(AtLeast, NaiveDate::from_ymd_opt(2020, 1, 2).unwrap()).into(),
+            TimeParam::Set(Is, set("znr")).into(),
+        ]);
+        for query in queries {
+            assert_eq!(parse(&query.to_param()), query, "{}", query.to_param());
+        }
+    }
+
+    #[test]
+    fn queries_round_trip() {
+        let t = |name: &str| Query::from(StringParam::Type(name.into()));
+        let queries = vec![
+            (t("goblin") | t("elf")) & !Query::from(BooleanParam::IsFunny),
+            (t("goblin") & t("legendary")) | (t("elf") & !t("legendary")),
+            !(t("goblin") & t("elf")) & !(t("goblin") | t("elf")),
+            Query::Not(Box::new(!t("goblin"))),
+            Query::from(StringParam::ExactName("Lightning Bolt".into()))
+                | RegexParam::Oracle("^{T}:".into()).into(),
+            Query::Custom("bolt".into()) & Query::Custom("order:spoiled".into()),
+        ];
+        for query in queries {
+            assert_eq!(parse(&query.to_param()), query, "{}", query.to_param());
+        }
+    }
+
+    #[test]
+    fn parse_syntax() {
+        use ComparisonExpr::*;
+        assert_eq!(parse(""), Query::And(vec![]));
+        assert_eq!(
+            parse("  T:Goblin and mv>=2  "),
+            Query::And(vec![
+                StringParam::Type("Goblin".into()).into(),
+                NumericParam::Cmc(AtLeastInclusive, 2).into(),
+            ])
+        );
+        assert_eq!(
+            parse("c:rg id:wubrg"),
+            Query::And(vec![
+                ColorParam::Color(
+                    AtLeastInclusive,
+                    Colors::from(&[Color::Red, Color::Green][..])
+                )
+                .into(),
+                ColorParam::ColorIdentity(
+                    AtMostInclusive,
+                    Colors::from(
+                        &[
+                            Color::White,
+                            Color::Blue,
+                            Color::Black,
+                            Color::Red,
+                            Color::Green
+                        ][..]
+                    )
+                )
+                .into(),
+            ])
+        );
+        assert_eq!(
+            parse("bolt or usd<0.5 or frame:glitter or a or order:spoiled or a>/b/"),
+            Query::Or(vec![
+                Query::Custom("bolt".into()),
+                NumericParam::UsdPrice(AtMost, Decimal::new(5, 1)).into(),
+                Query::Custom("frame:glitter".into()),
+                Query::Custom("a".into()),
+                Query::Custom("order:spoiled".into()),
+                Query::Custom("a>/b/".into()),
+            ])
+        );
+        assert_eq!(
+            parse("!fire cn>=50 cn:7 mv:2 kw:x"),
+            Query::And(vec![
+                StringParam::ExactName("fire".into()).into(),
+                StringParam::CollectorNumber(AtLeastInclusive, "50".into()).into(),
+                NumericParam::CollectorNumber(7).into(),
+                NumericParam::Cmc(Is, 2).into(),
+                Query::Custom("kw:x".into()),
+            ])
+        );
+        assert_eq!(
+            parse("produces:wu devotion:ggg otag:ramp DIRECTION:ASC"),
+            Query::And(vec![
+                ColorParam::Produces(
+                    AtLeastInclusive,
+                    Colors::from(&[Color::White, Color::Blue][..])
+                )
+                .into(),
+                StringParam::Devotion(AtLeastInclusive, "ggg".into()).into(),
+                StringParam::OracleTag("ramp".into()).into(),
+                OptionParam::Direction(SortDirection::Ascending).into(),
+            ])
+        );
+        assert_eq!(
+            parse("o:\"{T}: Add\" -(orzhov)"),
+            Query::And(vec![
+                StringParam::Oracle("{T}: Add".into()).into(),
+                !Query::Custom("orzhov".into()),
+            ])
+        );
+        assert_eq!(parse("in:paper"), GameParam::InGame(Game::Paper).into());
+        assert_eq!(
+            parse("in:ja"),
+            StringParam::PrintedInLang("ja".into()).into()
+        );
+
+        let nested = format!("{}t:goblin{}", "(-".repeat(100), ")".repeat(100));
+        let mut expected = Query::from(StringParam::Type("goblin".into()));
+        for _ in 0..100 {
+            expected = Query::Not(Box::new(expected));
+        }
+        assert_eq!(parse(&nested), expected);
+        for deep in &[
+            format!("{}t:goblin", "-".repeat(5000)),
+            format!("{}t:goblin{}", "(".repeat(5000), ")".repeat(5000)),
+        ] {
+            assert!(matches!(
+                Query::parse(deep),
+                Err(Error::InvalidQuery { .. })
+            ));
+        }
+
+        for invalid in &[
+            "(t:goblin",
+            "t:goblin)",
+            "()",
+            "t:goblin or",
+            "or t:goblin",
+            "- t:goblin",
+            "o:\"draw",
+            "o:/draw",
+            "t:",
+        ] {
+            assert!(
+                matches!(Query::parse(invalid), Err(Error::InvalidQuery { .. })),
+                "{}",
+                invalid
+            );
+        }
+    }
+}
@@ -0,0 +1,366 @@
+//! A search query as a tree of params combined with `and`, `or` and `not`.
+use std::ops;
+
+use serde::{Deserialize, Serialize};
+
+use crate::card::{BorderColor, Frame, FrameEffect, SecurityStamp};
+use crate::card_searcher::{
+    BooleanParam, ColorParam, FormatParam, GameParam, NotParam, NumericParam, OptionParam, Param,
+    RarityParam, RegexParam, Search, StringParam, TimeParam,
+};
+
+/// A search query, made of params combined with `and`, `or` and `not`.
+///
+/// Queries can be built with the variants directly, or with the `&`, `|` and
+/// `!` operators, which flatten nested groups of the same kind. Any type that
+/// implements [`Param`] can be used in a query with [`Query::param`], but
+/// the params of this module are kept typed.
+///
+/// When rendered, `and` is written as a space, like Scryfall expects, and `or`
+/// groups are always put between parentheses, since `and` binds tighter.
+/// Because of this a `Query` can be passed to a [`SearchBuilder`] along with
+/// other params, or searched on its own.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card_searcher::ComparisonExpr::AtMostInclusive;
+/// use scryfall::card_searcher::NumericParam::Cmc;
+/// use scryfall::card_searcher::StringParam::Type;
+/// use scryfall::card_searcher::{BooleanParam, Param, Query};
+///
+/// let tribes = Query::from(Type("goblin".into())) | Type("elf".into()).into();
+/// let query = tribes & !Query::from(BooleanParam::IsFunny) & Cmc(AtMostInclusive, 2).into();
+/// assert_eq!(query.to_param(), "(t:goblin or t:elf) -is:funny cmc<=2");
+/// ```
+///
+/// [`SearchBuilder`]: super::SearchBuilder
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Query {
+    /// Cards that match all of the queries.
+    And(Vec<Query>),
+    /// Cards that match any of the queries.
+    Or(Vec<Query>),
+    /// Cards that don't match the query.
+    Not(Box<Query>),
+    /// A [`BooleanParam`].
+    Boolean(BooleanParam),
+    /// A [`StringParam`].
+    String(StringParam),
+    /// A [`NumericParam`].
+    Numeric(NumericParam),
+    /// A [`RarityParam`].
+    Rarity(RarityParam),
+    /// A [`ColorParam`].
+    Color(ColorParam),
+    /// A [`FormatParam`].
+    Format(FormatParam),
+    /// A [`BorderColor`] param.
+    BorderColor(BorderColor),
+    /// A [`Frame`] param.
+    Frame(Frame),
+    /// A [`FrameEffect`] param.
+    FrameEffect(FrameEffect),
+    /// A [`GameParam`].
+    Game(GameParam),
+    /// A [`TimeParam`].
+    Time(TimeParam),
+    /// A [`SecurityStamp`] param.
+    SecurityStamp(SecurityStamp),
+    /// A [`RegexParam`].
+    Regex(RegexParam),
+    /// An [`OptionParam`].
+    Option(OptionParam),
+    /// Any other param, already written in Scryfall's syntax.
+    Custom(String),
+}
+
+impl Query {
+    /// Wraps any param in a query. Use `into` instead for the params of this
+    /// module, so they stay typed.
+    pub fn param(param: impl Param) -> Self {
+        Query::Custom(param.to_param())
+    }
+
+    /// Renders the query, skipping empty groups, along with how it combines
+    /// with the terms around it.
+    fn render(&self) -> (String, Shape) {
+        match self {
+            Query::And(queries) => {
+                let mut rendered = render_all(queries);
+                match rendered.len() {
+                    0 => (String::new(), Shape::Empty),
+                    1 => rendered.remove(0),
+                    _ => (join(&rendered, " "), Shape::And),
+                }
+            },
+            Query::Or(queries) => {
+                let mut rendered = render_all(queries);
+                match rendered.len() {
+                    0 => (String::new(), Shape::Empty),
+                    1 => rendered.remove(0),
+                    _ => (format!("({})", join(&rendered, " or ")), Shape::Term),
+                }
+            },
+            Query::Not(query) => match query.render() {
+                (_, Shape::Empty) => (String::new(), Shape::Empty),
+                (rendered, Shape::Term) => (format!("-{}", rendered), Shape::Not),
+                (rendered, Shape::And | Shape::Not) => (format!("-({})", rendered), Shape::Not),
+            },
+            Query::Custom(param) if param.is_empty() => (String::new(), Shape::Empty),
+            param => (param.render_param(), Shape::Term),
+        }
+    }
+
+    /// Renders a query that is a single param.
+    fn render_param(&self) -> String {
+        match self {
+            Query::Boolean(param) => param.to_param(),
+            Query::String(param) => param.to_param(),
+            Query::Numeric(param) => param.to_param(),
+            Query::Rarity(param) => param.to_param(),
+            Query::Color(param) => param.to_param(),
+            Query::Format(param) => param.to_param(),
+            Query::BorderColor(param) => param.to_param(),
+            Query::Frame(param) => param.to_param(),
+            Query::FrameEffect(param) => param.to_param(),
+            Query::Game(param) => param.to_param(),
+            Query::Time(param) => param.to_param(),
+            Query::SecurityStamp(param) => param.to_param(),
+            Query::Regex(param) => param.to_param(),
+            Query::Option(param) => param.to_param(),
+            Query::Custom(param) => param.clone(),
+            Query::And(_) | Query::Or(_) | Query::Not(_) => unreachable!("groups aren't params"),
+        }
+    }
+}
+
+/// How a rendered query combines with the terms around it, which decides if it
+/// needs parentheses to be negated.
+enum Shape {
+    /// Nothing was rendered.
+    Empty,
+    /// A single term, or an `or` group already between parentheses.
+    Term,
+    /// Several terms that are all required.
+    And,
+    /// A negated query.
+    Not,
+}
+
+/// Renders the queries that aren't empty.
+fn render_all(queries: &[Query]) -> Vec<(String, Shape)> {
+    queries
+        .iter()
+        .map(Query::render)
+        .filter(|(_, shape)| !matches!(shape, Shape::Empty))
+        .collect()
+}
+
+fn join(rendered: &[(String, Shape)], separator: &str) -> String {
+    rendered
+        .iter()
+        .map(|(query, _)| query.as_str())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+impl Param for Query {
+    fn to_param(&self) -> String {
+        self.render().0
+    }
+}
+
+impl Search for Query {
+    fn to_query(&self) -> String {
+        super::encode_search(&self.to_param())
+    }
+}
+
+/// Cards that match both queries.
+impl ops::BitAnd for Query {
+    type Output = Query;
+
+    fn bitand(self, other: Query) -> Query {
+        match (self, other) {
+            (Query::And(mut a), Query::And(b)) => {
+                a.extend(b);
+                Query::And(a)
+            },
+            (Query::And(mut a), b) => {
+                a.push(b);
+                Query::And(a)
+            },
+            (a, Query::And(mut b)) => {
+                b.insert(0, a);
+                Query::And(b)
+            },
+            (a, b) => Query::And(vec![a, b]),
+        }
+    }
+}
+
+/// Cards that match either query.
+impl ops::BitOr for Query {
+    type Output = Query;
+
+    fn bitor(self, other: Query) -> Query {
+        match (self, other) {
+            (Query::Or(mut a), Query::Or(b)) => {
+                a.extend(b);
+                Query::Or(a)
+            },
+            (Query::Or(mut a), b) => {
+                a.push(b);
+                Query::Or(a)
+            },
+            (a, Query::Or(mut b)) => {
+                b.insert(0, a);
+                Query::Or(b)
+            },
+            (a, b) => Query::Or(vec![a, b]),
+        }
+    }
+}
+
+/// Cards that don't match the query. Negating a negated query gives back the
+/// original one.
+impl ops::Not for Query {
+    type Output = Query;
+
+    fn not(self) -> Query {
+        match self {
+            Query::Not(query) => *query,
+            query => Query::Not(Box::new(query)),
+        }
+    }
+}
+
+macro_rules! impl_from_param {
+    ($($param:ident => $variant:ident),* $(,)?) => {
+        $(
+            impl From<$param> for Query {
+                fn from(param: $param) -> Self {
+                    Query::$variant(param)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_param! {
+    BooleanParam => Boolean,
+    StringParam => String,
+    NumericParam => Numeric,
+    RarityParam => Rarity,
+    ColorParam => Color,
+    FormatParam => Format,
+    BorderColor => BorderColor,
+    Frame => Frame,
+    FrameEffect => FrameEffect,
+    GameParam => Game,
+    TimeParam => Time,
+    SecurityStamp => SecurityStamp,
+    RegexParam => Regex,
+    OptionParam => Option,
+}
+
+impl<T: Param + Into<Query>> From<NotParam<T>> for Query {
+    fn from(param: NotParam<T>) -> Self {
+        !param.0.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card_searcher::ComparisonExpr::*;
+    use crate::card_searcher::{not, SearchBuilder};
+    use crate::format::Format;
+
+    fn t(name: &str) -> Query {
+        StringParam::Type(name.to_string()).into()
+    }
+
+    #[test]
+    fn render_with_precedence() {
+        let query = (t("goblin") | t("elf"))
+            & Query::Custom("-id:w".to_string())
+            & NumericParam::Cmc(AtMostInclusive, 2).into();
+        assert_eq!(query.to_param(), "(t:goblin or t:elf) -id:w cmc<=2");
+
+        let query = (t("goblin") & t("legendary")) | (t("elf") & !t("legendary"));
+        assert_eq!(
+            query.to_param(),
+            "(t:goblin t:legendary or t:elf -t:legendary)"
+        );
+
+        let query = !(t("goblin") & t("elf")) & !(t("goblin") | t("elf"));
+        assert_eq!(query.to_param(), "-(t:goblin t:elf) -(t:goblin or t:elf)");
+        assert_eq!((!!t("goblin")).to_param(), "t:goblin");
+        assert_eq!(
+            Query::Not(Box::new(Query::Not(Box::new(t("goblin"))))).to_param(),
+            "-(-t:goblin)"
+        );
+    }
+
+    #[test]
+    fn negate_nested_single_groups() {
+        let ab = || t("a") & t("b");
+        assert_eq!(Query::Or(vec![ab()]).to_param(), "t:a t:b");
+        assert_eq!((!Query::Or(vec![ab()])).to_param(), "-(t:a t:b)");
+        assert_eq!((!Query::And(vec![ab()])).to_param(), "-(t:a t:b)");
+        assert_eq!(
+            (!Query::And(vec![Query::Or(vec![Query::And(vec![ab()])])])).to_param(),
+            "-(t:a t:b)"
+        );
+        assert_eq!(
+            (!Query::And(vec![Query::Or(vec![]), ab()])).to_param(),
+            "-(t:a t:b)"
+        );
+        assert_eq!(
+            (!Query::Or(vec![t("a") | t("b")])).to_param(),
+            "-(t:a or t:b)"
+        );
+        assert_eq!((!Query::And(vec![!t("a")])).to_param(), "-(-t:a)");
+        assert_eq!((!Query::Or(vec![t("a")])).to_param(), "-t:a");
+        assert_eq!(
+            (t("c") & !Query::Or(vec![ab()])).to_param(),
+            "t:c -(t:a t:b)"
+        );
+    }
+
+    #[test]
+    fn flatten_and_skip_empty_groups() {
+        let query = t("a") & t("b") & (t("c") & t("d"));
+        assert_eq!(query, Query::And(vec![t("a"), t("b"), t("c"), t("d")]));
+        let query = t("a") | (t("b") | t("c"));
+        assert_eq!(query, Query::Or(vec![t("a"), t("b"), t("c")]));
+
+        let query = Query::And(vec![
+            Query::Or(vec![]),
+            Query::Or(vec![t("a")]),
+            !Query::And(vec![]),
+            FormatParam::Legal(Format::Modern).into(),
+        ]);
+        assert_eq!(query.to_param(), "t:a legal:modern");
+        assert_eq!(
+            Query::from(not(BooleanParam::IsFunny)).to_param(),
+            "-is:funny"
+        );
+    }
+
+    #[test]
+    fn search_with_other_params() {
+        let mut search = SearchBuilder::new();
+        search
+            .param(t("goblin") | t("elf"))
+            .param(NumericParam::Cmc(AtMost, 3));
+        assert!(search
+            .to_query()
+            .ends_with("&q=%28t%3Agoblin+or+t%3Aelf%29+cmc%3C3"));
+        assert_eq!(
+            (t("goblin") | t("elf")).to_query(),
+            "q=%28t%3Agoblin+or+t%3Aelf%29"
+        );
+    }
+}
@@ -7,13 +7,18 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::client::ScryfallClient;
 use crate::uri::Uri;
-use crate::util::CATALOG_URL;
+use crate::util::CATALOG_PATH;
 
 #[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 #[allow(missing_docs)]
 pub struct Catalog {
-    pub uri: Uri<Catalog>,
+    /// The link to this catalog. Catalogs that aren't in the `/catalog`
+    /// endpoints, like the results of
+    /// [`Card::autocomplete`](crate::Card::autocomplete), don't have one.
+    #[serde(default)]
+    pub uri: Option<Uri<Catalog>>,
     pub data: Vec<String>,
 }
 
@@ -28,7 +33,13 @@ impl Catalog {
     /// assert!(Catalog::card_names().unwrap().data.len() > 0)
     /// ```
     pub fn card_names() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("card-names")?).fetch()
+        Catalog::card_names_with(ScryfallClient::global())
+    }
+
+    /// Same as [`card_names`](#method.card_names), but the request is made with
+    /// the given client.
+    pub fn card_names_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("card-names")?).fetch_with(client)
     }
 
     /// Returns a list of all canonical artist names in Scryfall’s database.
@@ -42,7 +53,13 @@ impl Catalog {
     /// assert!(Catalog::artist_names().unwrap().data.len() > 0)
     /// ```
     pub fn artist_names() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("artist-names")?).fetch()
+        Catalog::artist_names_with(ScryfallClient::global())
+    }
+
+    /// Same as [`artist_names`](#method.artist_names), but the request is made
+    /// with the given client.
+    pub fn artist_names_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("artist-names")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all English words, of length 2 or more, that could
@@ -56,7 +73,13 @@ impl Catalog {
     /// assert!(Catalog::word_bank().unwrap().data.len() > 0)
     /// ```
     pub fn word_bank() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("word-bank")?).fetch()
+        Catalog::word_bank_with(ScryfallClient::global())
+    }
+
+    /// Same as [`word_bank`](#method.word_bank), but the request is made with
+    /// the given client.
+    pub fn word_bank_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("word-bank")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all creature types in Scryfall’s database. Values
@@ -68,7 +91,13 @@ impl Catalog {
     /// assert!(Catalog::creature_types().unwrap().data.len() > 0)
     /// ```
     pub fn creature_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("creature-types")?).fetch()
+        Catalog::creature_types_with(ScryfallClient::global())
+    }
+
+    /// Same as [`creature_types`](#method.creature_types), but the request is
+    /// made with the given client.
+    pub fn creature_types_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("creature-types")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all Planeswalker types in Scryfall’s database.
@@ -81,7 +110,13 @@ impl Catalog {
     /// assert!(Catalog::planeswalker_types().unwrap().data.len() > 0)
     /// ```
     pub fn planeswalker_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("planeswalker-types")?).fetch()
+        Catalog::planeswalker_types_with(ScryfallClient::global())
+    }
+
+    /// Same as [`planeswalker_types`](#method.planeswalker_types), but the
+    /// request is made with the given client.
+    pub fn planeswalker_types_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("planeswalker-types")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all Land types in Scryfall’s database. Values are
@@ -93,7 +128,13 @@ impl Catalog {
     /// assert!(Catalog::land_types().unwrap().data.len() > 0)
     /// ```
     pub fn land_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("land-types")?).fetch()
+        Catalog::land_types_with(ScryfallClient::global())
+    }
+
+    /// Same as [`land_types`](#method.land_types), but the request is made with
+    /// the given client.
+    pub fn land_types_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("land-types")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all artifact types in Scryfall’s database. Values
@@ -105,7 +146,13 @@ impl Catalog {
     /// assert!(Catalog::artifact_types().unwrap().data.len() > 0)
     /// ```
     pub fn artifact_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("artifact-types")?).fetch()
+        Catalog::artifact_types_with(ScryfallClient::global())
+    }
+
+    /// Same as [`artifact_types`](#method.artifact_types), but the request is
+    /// made with the given client.
+    pub fn artifact_types_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("artifact-types")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all enchantment types in Scryfall’s database.
@@ -118,7 +165,13 @@ impl Catalog {
     /// assert!(Catalog::enchantment_types().unwrap().data.len() > 0)
     /// ```
     pub fn enchantment_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("enchantment-types")?).fetch()
+        Catalog::enchantment_types_with(ScryfallClient::global())
+    }
+
+    /// Same as [`enchantment_types`](#method.enchantment_types), but the
+    /// request is made with the given client.
+    pub fn enchantment_types_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("enchantment-types")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all spell types in Scryfall’s database. Values are
@@ -130,7 +183,13 @@ impl Catalog {
     /// assert!(Catalog::spell_types().unwrap().data.len() > 0)
     /// ```
     pub fn spell_types() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("spell-types")?).fetch()
+        Catalog::spell_types_with(ScryfallClient::global())
+    }
+
+    /// Same as [`spell_types`](#method.spell_types), but the request is made
+    /// with the given client.
+    pub fn spell_types_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("spell-types")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all possible values for a creature or vehicle’s
@@ -143,7 +202,13 @@ impl Catalog {
     /// assert!(Catalog::powers().unwrap().data.len() > 0)
     /// ```
     pub fn powers() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("powers")?).fetch()
+        Catalog::powers_with(ScryfallClient::global())
+    }
+
+    /// Same as [`powers`](#method.powers), but the request is made with the
+    /// given client.
+    pub fn powers_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("powers")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all possible values for a creature or vehicle’s
@@ -156,7 +221,13 @@ impl Catalog {
     /// assert!(Catalog::toughnesses().unwrap().data.len() > 0)
     /// ```
     pub fn toughnesses() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("toughnesses")?).fetch()
+        Catalog::toughnesses_with(ScryfallClient::global())
+    }
+
+    /// Same as [`toughnesses`](#method.toughnesses), but the request is made
+    /// with the given client.
+    pub fn toughnesses_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("toughnesses")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all possible values for a Planeswalker’s loyalty in
@@ -169,7 +240,13 @@ impl Catalog {
     /// assert!(Catalog::loyalties().unwrap().data.len() > 0)
     /// ```
     pub fn loyalties() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("loyalties")?).fetch()
+        Catalog::loyalties_with(ScryfallClient::global())
+    }
+
+    /// Same as [`loyalties`](#method.loyalties), but the request is made with
+    /// the given client.
+    pub fn loyalties_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("loyalties")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all card watermarks in Scryfall’s database. Values
@@ -181,7 +258,13 @@ impl Catalog {
     /// assert!(Catalog::watermarks().unwrap().data.len() > 0)
     /// ```
     pub fn watermarks() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("watermarks")?).fetch()
+        Catalog::watermarks_with(ScryfallClient::global())
+    }
+
+    /// Same as [`watermarks`](#method.watermarks), but the request is made with
+    /// the given client.
+    pub fn watermarks_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("watermarks")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all keyword abilities in Scryfall’s database.
@@ -200,7 +283,13 @@ impl Catalog {
     /// );
     /// ```
     pub fn keyword_abilities() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("keyword-abilities")?).fetch()
+        Catalog::keyword_abilities_with(ScryfallClient::global())
+    }
+
+    /// Same as [`keyword_abilities`](#method.keyword_abilities), but the
+    /// request is made with the given client.
+    pub fn keyword_abilities_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("keyword-abilities")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all keyword actions in Scryfall’s database. Values
@@ -219,7 +308,13 @@ impl Catalog {
     /// );
     /// ```
     pub fn keyword_actions() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("keyword-actions")?).fetch()
+        Catalog::keyword_actions_with(ScryfallClient::global())
+    }
+
+    /// Same as [`keyword_actions`](#method.keyword_actions), but the request is
+    /// made with the given client.
+    pub fn keyword_actions_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("keyword-actions")?).fetch_with(client)
     }
 
     /// Returns a Catalog of all ability words in Scryfall’s database. Values
@@ -238,6 +333,226 @@ impl Catalog {
     /// );
     /// ```
     pub fn ability_words() -> crate::Result<Self> {
-        Uri::from(CATALOG_URL.join("ability-words")?).fetch()
+        Catalog::ability_words_with(ScryfallClient::global())
+    }
+
+    /// Same as [`ability_words`](#method.ability_words), but the request is
+    /// made with the given client.
+    pub fn ability_words_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("ability-words")?).fetch_with(client)
+    }
+}
+
+#[cfg(feature = "async")]
+impl Catalog {
+    /// Asynchronous version of [`card_names`](#method.card_names).
+    pub async fn card_names_async() -> crate::Result<Self> {
+        Catalog::card_names_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`card_names_async`](#method.card_names_async), but the request
+    /// is made with the given client.
+    pub async fn card_names_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("card-names")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`artist_names`](#method.artist_names).
+    pub async fn artist_names_async() -> crate::Result<Self> {
+        Catalog::artist_names_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`artist_names_async`](#method.artist_names_async), but the
+    /// request is made with the given client.
+    pub async fn artist_names_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("artist-names")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`word_bank`](#method.word_bank).
+    pub async fn word_bank_async() -> crate::Result<Self> {
+        Catalog::word_bank_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`word_bank_async`](#method.word_bank_async), but the request is
+    /// made with the given client.
+    pub async fn word_bank_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("word-bank")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`creature_types`](#method.creature_types).
+    pub async fn creature_types_async() -> crate::Result<Self> {
+        Catalog::creature_types_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`creature_types_async`](#method.creature_types_async), but the
+    /// request is made with the given client.
+    pub async fn creature_types_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("creature-types")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of
+    /// [`planeswalker_types`](#method.planeswalker_types).
+    pub async fn planeswalker_types_async() -> crate::Result<Self> {
+        Catalog::planeswalker_types_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`planeswalker_types_async`](#method.planeswalker_types_async),
+    /// but the request is made with the given client.
+    pub async fn planeswalker_types_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("planeswalker-types")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`land_types`](#method.land_types).
+    pub async fn land_types_async() -> crate::Result<Self> {
+        Catalog::land_types_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`land_types_async`](#method.land_types_async), but the request
+    /// is made with the given client.
+    pub async fn land_types_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("land-types")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`artifact_types`](#method.artifact_types).
+    pub async fn artifact_types_async() -> crate::Result<Self> {
+        Catalog::artifact_types_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`artifact_types_async`](#method.artifact_types_async), but the
+    /// request is made with the given client.
+    pub async fn artifact_types_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("artifact-types")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of
+    /// [`enchantment_types`](#method.enchantment_types).
+    pub async fn enchantment_types_async() -> crate::Result<Self> {
+        Catalog::enchantment_types_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`enchantment_types_async`](#method.enchantment_types_async),
+    /// but the request is made with the given client.
+    pub async fn enchantment_types_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("enchantment-types")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`spell_types`](#method.spell_types).
+    pub async fn spell_types_async() -> crate::Result<Self> {
+        Catalog::spell_types_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`spell_types_async`](#method.spell_types_async), but the
+    /// request is made with the given client.
+    pub async fn spell_types_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("spell-types")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`powers`](#method.powers).
+    pub async fn powers_async() -> crate::Result<Self> {
+        Catalog::powers_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`powers_async`](#method.powers_async), but the request is made
+    /// with the given client.
+    pub async fn powers_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("powers")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`toughnesses`](#method.toughnesses).
+    pub async fn toughnesses_async() -> crate::Result<Self> {
+        Catalog::toughnesses_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`toughnesses_async`](#method.toughnesses_async), but the
+    /// request is made with the given client.
+    pub async fn toughnesses_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("toughnesses")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`loyalties`](#method.loyalties).
+    pub async fn loyalties_async() -> crate::Result<Self> {
+        Catalog::loyalties_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`loyalties_async`](#method.loyalties_async), but the request is
+    /// made with the given client.
+    pub async fn loyalties_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("loyalties")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`watermarks`](#method.watermarks).
+    pub async fn watermarks_async() -> crate::Result<Self> {
+        Catalog::watermarks_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`watermarks_async`](#method.watermarks_async), but the request
+    /// is made with the given client.
+    pub async fn watermarks_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("watermarks")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of
+    /// [`keyword_abilities`](#method.keyword_abilities).
+    pub async fn keyword_abilities_async() -> crate::Result<Self> {
+        Catalog::keyword_abilities_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`keyword_abilities_async`](#method.keyword_abilities_async),
+    /// but the request is made with the given client.
+    pub async fn keyword_abilities_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("keyword-abilities")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`keyword_actions`](#method.keyword_actions).
+    pub async fn keyword_actions_async() -> crate::Result<Self> {
+        Catalog::keyword_actions_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`keyword_actions_async`](#method.keyword_actions_async), but
+    /// the request is made with the given client.
+    pub async fn keyword_actions_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("keyword-actions")?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`ability_words`](#method.ability_words).
+    pub async fn ability_words_async() -> crate::Result<Self> {
+        Catalog::ability_words_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`ability_words_async`](#method.ability_words_async), but the
+    /// request is made with the given client.
+    pub async fn ability_words_async_with(client: &ScryfallClient) -> crate::Result<Self> {
+        Uri::from(client.endpoint(CATALOG_PATH)?.join("ability-words")?)
+            .fetch_async_with(client)
+            .await
     }
 }
@@ -0,0 +1,570 @@
+//! This module defines [`ScryfallClient`], the HTTP client through which every
+//! request to the Scryfall API is made.
+//!
+//! The functions in this crate that don't take a client, such as
+//! [`Card::named`][crate::Card::named], use a default client that is shared by
+//! the whole process. Each of them has a `_with` counterpart, like
+//! [`Card::named_with`][crate::Card::named_with], that takes the client to use
+//! as its first argument. This allows several configurations to coexist in the
+//! same program.
+//!
+//! Requests are spaced out by a [`RateLimiter`], which by default is shared by
+//! all clients in the process, as Scryfall
+//! [asks](https://scryfall.com/docs/api#rate-limits-and-good-citizenship).
+//! Requests that fail with a transient error are retried according to the
+//! client's [`RetryPolicy`].
+//!
+//! With the `async` feature enabled, a client can also make its requests
+//! asynchronously. The asynchronous functions of this crate have an `_async`
+//! suffix, and need to run inside a [tokio](https://tokio.rs) runtime.
+//!
+//! # Examples
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use scryfall::client::ScryfallClient;
+//! use scryfall::Card;
+//!
+//! let client = ScryfallClient::builder()
+//!     .with_timeout(Duration::from_secs(10))
+//!     .with_user_agent("my-app/1.0")
+//!     .build()
+//!     .unwrap();
+//! let bolt = Card::named_with(&client, "Lightning Bolt").unwrap();
+//! assert_eq!(bolt.name, "Lightning Bolt");
+//! ```
+mod rate_limiter;
+mod retry;
+#[cfg(test)]
+pub(crate) mod test_server;
+
+use std::io::Read;
+use std::thread;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use ureq::{Agent, AgentBuilder, Proxy, Response};
+use url::Url;
+
+pub use self::rate_limiter::RateLimiter;
+pub use self::retry::RetryPolicy;
+use crate::error::Error;
+use crate::util::ROOT_URL;
+
+/// The user agent sent by clients that don't configure one.
+const DEFAULT_USER_AGENT: &str = concat!("scryfall-rs/", env!("CARGO_PKG_VERSION"));
+
+static DEFAULT_CLIENT: Lazy<ScryfallClient> = Lazy::new(ScryfallClient::new);
+
+/// A client for the Scryfall API.
+///
+/// A client owns the underlying connection pool, the base URL that endpoints
+/// are resolved against, the [`RateLimiter`] its requests go through and the
+/// [`RetryPolicy`] applied to failed requests. Cloning a client is cheap, and
+/// the clones share the same connection pool and rate limiter.
+///
+/// Use [`ScryfallClient::builder`] to create a client with a custom
+/// configuration, or [`ScryfallClient::new`] for the default one.
+#[derive(Clone, Debug)]
+pub struct ScryfallClient {
+    agent: Agent,
+    base_url: Url,
+    rate_limiter: RateLimiter,
+    retry_policy: RetryPolicy,
+    #[cfg(feature = "async")]
+    http: reqwest::Client,
+}
+
+impl Default for ScryfallClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScryfallClient {
+    /// Creates a client with the default configuration.
+    pub fn new() -> Self {
+        ScryfallClientBuilder::new()
+            .build()
+            .expect("the default client configuration is valid")
+    }
+
+    /// Creates a builder to configure a new client.
+    pub fn builder() -> ScryfallClientBuilder {
+        ScryfallClientBuilder::new()
+    }
+
+    /// Returns the client used by the functions of this crate that don't take
+    /// a client as an argument.
+    pub fn global() -> &'static ScryfallClient {
+        &DEFAULT_CLIENT
+    }
+
+    /// The base URL against which the API endpoints are resolved.
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// The rate limiter this client's requests go through.
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// The policy applied to this client's failed requests.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Resolves an endpoint path, such as `cards/`, against the base URL.
+    pub(crate) fn endpoint(&self, path: &str) -> crate::Result<Url> {
+        Ok(self.base_url.join(path)?)
+    }
+
+    /// Makes a GET request to `url` and deserializes the response body into a
+    /// `T`.
+    pub(crate) fn fetch<T: DeserializeOwned>(&self, url: &Url) -> crate::Result<T> {
+        Ok(serde_json::from_reader(self.get(url)?.into_reader())?)
+    }
+
+    /// Makes a POST request to `url` with `body` serialized as JSON, and
+    /// deserializes the response body into a `T`.
+    pub(crate) fn post<B: Serialize, T: DeserializeOwned>(
+        &self,
+        url: &Url,
+        body: &B,
+    ) -> crate::Result<T> {
+        let body = serde_json::to_vec(body)?;
+        let response = self.send("POST", url, Some(&body))?;
+        Ok(serde_json::from_reader(response.into_reader())?)
+    }
+
+    /// Makes a GET request to `url`, returning the response if it was
+    /// successful.
+    pub(crate) fn get(&self, url: &Url) -> crate::Result<Response> {
+        self.send("GET", url, None)
+    }
+
+    /// Makes a request to `url`, with a JSON `body` if there is one, returning
+    /// the response if it was successful.
+    ///
+    /// Every request waits for the rate limiter, and failed requests are
+    /// retried according to the retry policy.
+    fn send(&self, method: &str, url: &Url, body: Option<&[u8]>) -> crate::Result<Response> {
+        let mut attempts = 0;
+        loop {
+            self.rate_limiter.acquire();
+            attempts += 1;
+            let request = self.agent.request_url(method, url);
+            let result = match body {
+                Some(body) => request
+                    .set("Content-Type", "application/json")
+                    .send_bytes(body),
+                None => request.call(),
+            };
+            let response = match result {
+                Ok(response) if (200..=299).contains(&response.status()) => return Ok(response),
+                Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+                Err(error) => return Err(Error::UreqError(Box::new(error), url.to_string())),
+            };
+
+            let status = response.status();
+            let status_text = response.status_text().to_string();
+            let retry_after = response.header("Retry-After").map(String::from);
+            let mut body = Vec::new();
+            // The body is only used to describe the error, so it's fine if it
+            // can't be read.
+            let _ = response.into_reader().read_to_end(&mut body);
+            self.check_retry(attempts, status, status_error(status, &status_text, &body))?;
+            thread::sleep(self.retry_policy.delay(attempts, retry_after.as_deref()));
+        }
+    }
+
+    /// Decides whether a request whose last attempt failed with `status`
+    /// should be retried. If not, returns the error to report.
+    fn check_retry(&self, attempts: u32, status: u16, error: Error) -> crate::Result<()> {
+        if !RetryPolicy::is_retryable(status) {
+            Err(error)
+        } else if !self.retry_policy.can_retry(attempts) {
+            Err(if attempts > 1 {
+                Error::RetriesExhausted {
+                    attempts,
+                    status,
+                    source: Box::new(error),
+                }
+            } else {
+                error
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl ScryfallClient {
+    /// Asynchronously makes a GET request to `url` and deserializes the
+    /// response body into a `T`.
+    pub(crate) async fn fetch_async<T: DeserializeOwned>(&self, url: &Url) -> crate::Result<T> {
+        let response = self.get_async(url).await?;
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| Error::ReqwestError(e, url.to_string()))?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// Asynchronously makes a POST request to `url` with `body` serialized as
+    /// JSON, and deserializes the response body into a `T`.
+    pub(crate) async fn post_async<B: Serialize, T: DeserializeOwned>(
+        &self,
+        url: &Url,
+        body: &B,
+    ) -> crate::Result<T> {
+        let body = serde_json::to_vec(body)?;
+        let response = self
+            .send_async(reqwest::Method::POST, url, Some(&body))
+            .await?;
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| Error::ReqwestError(e, url.to_string()))?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    /// Asynchronously makes a GET request to `url`, returning the response if
+    /// it was successful.
+    pub(crate) async fn get_async(&self, url: &Url) -> crate::Result<reqwest::Response> {
+        self.send_async(reqwest::Method::GET, url, None).await
+    }
+
+    /// Asynchronously makes a request to `url`, with a JSON `body` if there is
+    /// one, returning the response if it was successful.
+    ///
+    /// Like [`send`](#method.send), every request waits for the rate limiter,
+    /// and failed requests are retried according to the retry policy.
+    async fn send_async(
+        &self,
+        method: reqwest::Method,
+        url: &Url,
+        body: Option<&[u8]>,
+    ) -> crate::Result<reqwest::Response> {
+        let mut attempts = 0;
+        loop {
+            tokio::time::sleep(self.rate_limiter.reserve()).await;
+            attempts += 1;
+            let mut request = self.http.request(method.clone(), url.clone());
+            if let Some(body) = body {
+                request = request
+                    .header("Content-Type", "application/json")
+                    .body(body.to_vec());
+            }
+            let response = request
+                .send()
+                .await
+                .map_err(|e| Error::ReqwestError(e, url.to_string()))?;
+            if response.status().is_success() {
+                return Ok(response);
+            }
+
+            let status = response.status();
+            let retry_after = response
+                .headers()
+                .get("Retry-After")
+                .and_then(|value| value.to_str().ok())
+                .map(String::from);
+            let body = response.bytes().await.unwrap_or_default();
+            let error = status_error(
+                status.as_u16(),
+                status.canonical_reason().unwrap_or_default(),
+                &body,
+            );
+            self.check_retry(attempts, status.as_u16(), error)?;
+            tokio::time::sleep(self.retry_policy.delay(attempts, retry_after.as_deref())).await;
+        }
+    }
+}
+
+/// Turns an unsuccessful response into an error. Scryfall describes client
+/// errors with an [error object](https://scryfall.com/docs/api/errors), which
+/// is used when the body contains one.
+fn status_error(status: u16, status_text: &str, body: &[u8]) -> Error {
+    if (400..=499).contains(&status) {
+        if let Ok(error) = serde_json::from_slice(body) {
+            return Error::ScryfallError(error);
+        }
+    }
+    Error::HttpError(status, status_text.to_string())
+}
+
+/// A builder for [`ScryfallClient`].
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use scryfall::client::ScryfallClient;
+///
+/// let client = ScryfallClient::builder()
+///     .with_base_url("http://localhost:8080/".parse().unwrap())
+///     .with_timeout(Duration::from_secs(5))
+///     .build()
+///     .unwrap();
+/// assert_eq!(client.base_url().as_str(), "http://localhost:8080/");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScryfallClientBuilder {
+    base_url: Url,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: String,
+    proxy: Option<String>,
+    rate_limiter: RateLimiter,
+    retry_policy: RetryPolicy,
+}
+
+impl Default for ScryfallClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScryfallClientBuilder {
+    /// Create a new client builder with the default values.
+    pub fn new() -> Self {
+        ScryfallClientBuilder {
+            base_url: ROOT_URL.clone(),
+            timeout: None,
+            connect_timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            proxy: None,
+            rate_limiter: RateLimiter::global().clone(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Change the base URL the API endpoints are resolved against. (default:
+    /// `https://api.scryfall.com/`)
+    ///
+    /// A trailing `/` is added to the path if it's missing.
+    pub fn with_base_url(&mut self, mut url: Url) -> &mut Self {
+        if !url.path().ends_with('/') {
+            let path = format!("{}/", url.path());
+            url.set_path(&path);
+        }
+        self.base_url = url;
+        self
+    }
+
+    /// Change the timeout for the whole request, including reading the
+    /// response body. (default: no timeout)
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Change the timeout for establishing the connection. (default: no
+    /// timeout)
+    pub fn with_connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Change the `User-Agent` header sent with every request. (default:
+    /// `scryfall-rs/<version>`)
+    pub fn with_user_agent(&mut self, user_agent: &str) -> &mut Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Send every request through a proxy. The format of `proxy` is
+    /// `<protocol>://<user>:<password>@<host>:port`, where all parts except
+    /// the host are optional.
+    pub fn with_proxy(&mut self, proxy: &str) -> &mut Self {
+        self.proxy = Some(proxy.to_string());
+        self
+    }
+
+    /// Change the rate limiter the client's requests go through. (default:
+    /// [`RateLimiter::global`])
+    ///
+    /// Use [`RateLimiter::disabled`] to make requests as fast as possible, for
+    /// example when testing against a local server.
+    pub fn with_rate_limiter(&mut self, rate_limiter: RateLimiter) -> &mut Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Change the policy used to retry failed requests. (default:
+    /// [`RetryPolicy::default`])
+    ///
+    /// Use [`RetryPolicy::never`] to disable retries.
+    pub fn with_retry_policy(&mut self, retry_policy: RetryPolicy) -> &mut Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Creates the client.
+    ///
+    /// This fails if the proxy configuration couldn't be parsed.
+    pub fn build(&self) -> crate::Result<ScryfallClient> {
+        Ok(ScryfallClient {
+            agent: self.build_agent()?,
+            base_url: self.base_url.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            retry_policy: self.retry_policy.clone(),
+            #[cfg(feature = "async")]
+            http: self.build_http()?,
+        })
+    }
+
+    fn build_agent(&self) -> crate::Result<Agent> {
+        let mut agent = AgentBuilder::new().user_agent(&self.user_agent);
+        if let Some(timeout) = self.timeout {
+            agent = agent.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            agent = agent.timeout_connect(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            let proxy =
+                Proxy::new(proxy).map_err(|e| Error::UreqError(Box::new(e), proxy.clone()))?;
+            agent = agent.proxy(proxy);
+        }
+        Ok(agent.build())
+    }
+
+    #[cfg(feature = "async")]
+    fn build_http(&self) -> crate::Result<reqwest::Client> {
+        let mut http = reqwest::Client::builder().user_agent(&self.user_agent);
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            let proxy =
+                reqwest::Proxy::all(proxy).map_err(|e| Error::ReqwestError(e, proxy.clone()))?;
+            http = http.proxy(proxy);
+        }
+        http.build()
+            .map_err(|e| Error::ReqwestError(e, self.base_url.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_server::{TestResponse, TestServer};
+    use super::*;
+    use crate::catalog::Catalog;
+
+    const CATALOG: &str = r#"{"object":"catalog","uri":"https://api.scryfall.com/catalog/powers","total_values":1,"data":["1"]}"#;
+    const NOT_FOUND: &str =
+        r#"{"object":"error","code":"not_found","status":404,"details":"No card found."}"#;
+
+    #[test]
+    fn base_url_gets_trailing_slash() {
+        let client = ScryfallClient::builder()
+            .with_base_url("http://localhost:1234/api".parse().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url().as_str(), "http://localhost:1234/api/");
+        assert_eq!(
+            client.endpoint("cards/").unwrap().as_str(),
+            "http://localhost:1234/api/cards/"
+        );
+    }
+
+    #[test]
+    fn invalid_proxy() {
+        assert!(ScryfallClient::builder()
+            .with_proxy("ftp://localhost:21")
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn retries_transient_errors() {
+        let server = TestServer::new(vec![
+            TestResponse::new(503, ""),
+            TestResponse::new(429, "").with_header("Retry-After", "0"),
+            TestResponse::json(CATALOG),
+        ]);
+        let catalog = Catalog::powers_with(&server.client()).unwrap();
+        assert_eq!(catalog.data, ["1"]);
+        assert_eq!(server.requests().len(), 3);
+        assert!(server
+            .requests()
+            .iter()
+            .all(|r| r.method == "GET" && r.path == "/catalog/powers"));
+    }
+
+    #[test]
+    fn retries_exhausted() {
+        let server = TestServer::new(vec![
+            TestResponse::new(500, ""),
+            TestResponse::new(502, ""),
+            TestResponse::new(503, ""),
+        ]);
+        match Catalog::powers_with(&server.client()).unwrap_err() {
+            Error::RetriesExhausted {
+                attempts: 3,
+                status: 503,
+                source,
+            } => assert!(matches!(*source, Error::HttpError(503, _))),
+            other => panic!("Wrong error type: {0} {0:?}", other),
+        }
+    }
+
+    #[test]
+    fn client_errors_are_not_retried() {
+        let server = TestServer::new(vec![TestResponse::new(404, NOT_FOUND)]);
+        match Catalog::powers_with(&server.client()).unwrap_err() {
+            Error::ScryfallError(e) => assert_eq!(e.details, "No card found."),
+            other => panic!("Wrong error type: {0} {0:?}", other),
+        }
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn retries_disabled() {
+        let server = TestServer::new(vec![TestResponse::new(503, "")]);
+        let client = ScryfallClient::builder()
+            .with_base_url(server.url().clone())
+            .with_rate_limiter(RateLimiter::disabled())
+            .with_retry_policy(RetryPolicy::never())
+            .build()
+            .unwrap();
+        assert!(matches!(
+            Catalog::powers_with(&client).unwrap_err(),
+            Error::HttpError(503, _)
+        ));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_retries_transient_errors() {
+        let server = TestServer::new(vec![
+            TestResponse::new(503, ""),
+            TestResponse::json(CATALOG),
+        ]);
+        let catalog = Catalog::powers_async_with(&server.client()).await.unwrap();
+        assert_eq!(catalog.data, ["1"]);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn async_client_errors() {
+        let server = TestServer::new(vec![TestResponse::new(404, NOT_FOUND)]);
+        match Catalog::powers_async_with(&server.client())
+            .await
+            .unwrap_err()
+        {
+            Error::ScryfallError(e) => assert_eq!(e.details, "No card found."),
+            other => panic!("Wrong error type: {0} {0:?}", other),
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! A token bucket used to space out requests to the Scryfall API.
+//!
+//! Scryfall [asks](https://scryfall.com/docs/api#rate-limits-and-good-citizenship)
+//! that clients wait 50 to 100 milliseconds between requests, and will answer
+//! with `429 Too Many Requests` to clients that don't.
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+
+/// The default number of requests that can be made in a burst.
+const DEFAULT_BURST: u32 = 1;
+/// The default time it takes for a new request to be allowed.
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+static GLOBAL_LIMITER: Lazy<RateLimiter> =
+    Lazy::new(|| RateLimiter::new(DEFAULT_BURST, DEFAULT_INTERVAL));
+
+/// A token bucket rate limiter that can be shared between threads.
+///
+/// The bucket holds up to `burst` tokens, and a new token is added every
+/// `interval`. Each request takes a token, waiting for one to become available
+/// if the bucket is empty.
+///
+/// Cloning a `RateLimiter` produces a handle to the same bucket, so clones
+/// limit requests together. Every [`ScryfallClient`] uses
+/// [`RateLimiter::global`] unless configured otherwise, which means that all
+/// requests made by the process share the same limit.
+///
+/// [`ScryfallClient`]: crate::client::ScryfallClient
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use scryfall::client::{RateLimiter, ScryfallClient};
+///
+/// // Allow bursts of up to 5 requests, refilling one every 50ms.
+/// let limiter = RateLimiter::new(5, Duration::from_millis(50));
+/// let client = ScryfallClient::builder()
+///     .with_rate_limiter(limiter)
+///     .build()
+///     .unwrap();
+///
+/// // Requests to a local server don't need to be limited.
+/// let local = ScryfallClient::builder()
+///     .with_base_url("http://localhost:8080/".parse().unwrap())
+///     .with_rate_limiter(RateLimiter::disabled())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// The maximum number of tokens and how long each one takes to refill, or
+    /// `None` if the limiter is disabled.
+    rate: Option<(u32, Duration)>,
+    /// The available tokens. This is negative when requests are waiting for
+    /// tokens that haven't been added yet.
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter that allows bursts of up to `burst` requests, and
+    /// after that one request every `interval`.
+    ///
+    /// # Panics
+    /// Panics if `burst` is 0.
+    pub fn new(burst: u32, interval: Duration) -> Self {
+        assert!(burst > 0, "a rate limiter must allow at least one request");
+        RateLimiter {
+            bucket: Arc::new(Mutex::new(Bucket {
+                rate: Some((burst, interval)),
+                tokens: burst.into(),
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+
+    /// Creates a limiter that never waits.
+    pub fn disabled() -> Self {
+        RateLimiter {
+            bucket: Arc::new(Mutex::new(Bucket {
+                rate: None,
+                tokens: 0.0,
+                last_refill: Instant::now(),
+            })),
+        }
+    }
+
+    /// Returns the limiter shared by all clients that weren't configured with
+    /// a different one. By default it allows one request every 100ms.
+    pub fn global() -> &'static RateLimiter {
+        &GLOBAL_LIMITER
+    }
+
+    /// Changes the rate of this limiter, and of all its clones, to allow
+    /// bursts of up to `burst` requests, and after that one request every
+    /// `interval`.
+    ///
+    /// # Panics
+    /// Panics if `burst` is 0.
+    pub fn set_rate(&self, burst: u32, interval: Duration) {
+        assert!(burst > 0, "a rate limiter must allow at least one request");
+        let mut bucket = self.lock();
+        bucket.rate = Some((burst, interval));
+        bucket.tokens = burst.into();
+        bucket.last_refill = Instant::now();
+    }
+
+    /// Disables this limiter, and all its clones, so that requests never
+    /// wait.
+    pub fn disable(&self) {
+        self.lock().rate = None;
+    }
+
+    /// Checks whether this limiter is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.lock().rate.is_some()
+    }
+
+    /// Blocks the current thread until a request can be made.
+    pub fn acquire(&self) {
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+    }
+
+    /// Takes a token from the bucket, returning how long the caller has to
+    /// wait before making its request.
+    pub(crate) fn reserve(&self) -> Duration {
+        let mut bucket = self.lock();
+        let (burst, interval) = match bucket.rate {
+            Some(rate) => rate,
+            None => return Duration::ZERO,
+        };
+        if interval.is_zero() {
+            return Duration::ZERO;
+        }
+
+        let now = Instant::now();
+        let refilled =
+            now.duration_since(bucket.last_refill).as_secs_f64() / interval.as_secs_f64();
+        bucket.tokens = (bucket.tokens + refilled).min(burst.into());
+        bucket.last_refill = now;
+
+        bucket.tokens -= 1.0;
+        if bucket.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            interval.mul_f64(-bucket.tokens)
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Bucket> {
+        // The bucket is always left in a consistent state, so a poisoned lock
+        // can be used safely.
+        self.bucket.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn burst_does_not_wait() {
+        let limiter = RateLimiter::new(3, Duration::from_secs(60));
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire();
+        }
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(limiter.reserve() > Duration::from_secs(30));
+    }
+
+    #[test]
+    fn requests_are_spaced() {
+        let limiter = RateLimiter::new(1, Duration::from_millis(30));
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.acquire();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[test]
+    fn clones_share_the_bucket() {
+        let limiter = RateLimiter::new(1, Duration::from_millis(30));
+        let start = Instant::now();
+        let handles = (0..4)
+            .map(|_| {
+                let limiter = limiter.clone();
+                thread::spawn(move || limiter.acquire())
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[test]
+    fn disabled_never_waits() {
+        let limiter = RateLimiter::new(1, Duration::from_secs(60));
+        limiter.acquire();
+        limiter.clone().disable();
+        assert!(!limiter.is_enabled());
+        assert_eq!(limiter.reserve(), Duration::ZERO);
+        assert_eq!(RateLimiter::disabled().reserve(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,172 @@
+//! The policy deciding when and how failed requests are retried.
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+/// Describes how requests that fail with a transient error are retried.
+///
+/// A request is retried when Scryfall answers with `429 Too Many Requests` or
+/// with any `5xx` status. Between attempts the client waits for an
+/// exponentially growing, randomly jittered delay, or for as long as the
+/// response's `Retry-After` header asks, up to `max_backoff`.
+///
+/// When the last attempt fails, the error is wrapped in
+/// [`Error::RetriesExhausted`][crate::error::Error::RetriesExhausted].
+///
+/// # Examples
+/// ```rust
+/// use std::time::Duration;
+///
+/// use scryfall::client::{RetryPolicy, ScryfallClient};
+///
+/// let client = ScryfallClient::builder()
+///     .with_retry_policy(RetryPolicy {
+///         max_attempts: 5,
+///         initial_backoff: Duration::from_secs(1),
+///         ..Default::default()
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of times a request is made, including the first
+    /// one. A value of 0 or 1 disables retries.
+    pub max_attempts: u32,
+    /// The delay before the first retry. Each following retry waits twice as
+    /// long as the previous one.
+    pub initial_backoff: Duration,
+    /// The longest delay between two attempts, including delays requested by
+    /// a `Retry-After` header.
+    pub max_backoff: Duration,
+    /// Whether to wait for the delay requested by a `Retry-After` header,
+    /// when the response has one.
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries a request.
+    pub fn never() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Checks whether a response with this status code should be retried.
+    pub fn is_retryable(status: u16) -> bool {
+        status == 429 || (500..=599).contains(&status)
+    }
+
+    /// Checks whether a request that has been attempted `attempts` times can
+    /// be attempted again.
+    pub(crate) fn can_retry(&self, attempts: u32) -> bool {
+        attempts < self.max_attempts
+    }
+
+    /// How long to wait after the request failed for the `attempt`th time,
+    /// given the `Retry-After` header of the response, if any.
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<&str>) -> Duration {
+        if self.respect_retry_after {
+            if let Some(delay) = retry_after.and_then(parse_retry_after) {
+                return delay.min(self.max_backoff);
+            }
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+        // Wait at least half of the backoff, plus a random part of the other
+        // half, so that clients that failed together don't retry together.
+        let half = backoff / 2;
+        half + half.mul_f64(random_fraction())
+    }
+}
+
+/// Parses the value of a `Retry-After` header, which is either a number of
+/// seconds or an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        date.with_timezone(&Utc)
+            .signed_duration_since(Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+/// A random number in `[0, 1)`. This doesn't need to be of good quality, it
+/// only spreads retries apart.
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish() >> 11;
+    bits as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retryable_statuses() {
+        for status in &[429, 500, 502, 503, 504] {
+            assert!(RetryPolicy::is_retryable(*status));
+        }
+        for status in &[200, 400, 404, 422] {
+            assert!(!RetryPolicy::is_retryable(*status));
+        }
+    }
+
+    #[test]
+    fn backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(1000),
+            respect_retry_after: true,
+        };
+        let bounds = [(1, 50, 100), (2, 100, 200), (3, 200, 400), (8, 500, 1000)];
+        for &(attempt, min, max) in &bounds {
+            let delay = policy.delay(attempt, None);
+            assert!(delay >= Duration::from_millis(min), "{:?}", delay);
+            assert!(delay <= Duration::from_millis(max), "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn retry_after_header() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(1, Some("7")), Duration::from_secs(7));
+        assert_eq!(policy.delay(1, Some("86400")), policy.max_backoff);
+        assert_eq!(
+            policy.delay(1, Some("Wed, 21 Oct 2015 07:28:00 GMT")),
+            Duration::ZERO
+        );
+        assert!(policy.delay(1, Some("garbage")) <= policy.initial_backoff);
+
+        let ignoring = RetryPolicy {
+            respect_retry_after: false,
+            ..Default::default()
+        };
+        assert!(ignoring.delay(1, Some("7")) <= ignoring.initial_backoff);
+    }
+}
@@ -0,0 +1,145 @@
+//! A minimal HTTP server that answers requests with canned responses, used to
+//! test the client without reaching the real API.
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use url::Url;
+
+use crate::client::{RateLimiter, RetryPolicy, ScryfallClient};
+
+/// A response the server answers a request with.
+pub(crate) struct TestResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl TestResponse {
+    pub(crate) fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        TestResponse {
+            status,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: body.into(),
+        }
+    }
+
+    pub(crate) fn json(body: &str) -> Self {
+        TestResponse::new(200, body)
+    }
+
+    pub(crate) fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A request received by the server.
+#[derive(Clone, Debug)]
+pub(crate) struct TestRequest {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) body: String,
+}
+
+/// A server that answers each request with the next of its responses, and
+/// stops once they run out. The server runs on a background thread that isn't
+/// joined, since it may still be waiting for requests that never come.
+pub(crate) struct TestServer {
+    url: Url,
+    requests: Arc<Mutex<Vec<TestRequest>>>,
+}
+
+impl TestServer {
+    pub(crate) fn new(responses: Vec<TestResponse>) -> Self {
+        TestServer::from_fn(|_| responses)
+    }
+
+    /// Creates a server whose responses are built from its base URL, so that
+    /// they can link to other resources of the server.
+    pub(crate) fn from_fn(responses: impl FnOnce(&Url) -> Vec<TestResponse>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let responses = responses(&url);
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                received.lock().unwrap().push(read_request(&mut reader));
+                let mut stream = reader.into_inner();
+                write!(stream, "HTTP/1.1 {} Test\r\n", response.status).unwrap();
+                for (name, value) in &response.headers {
+                    write!(stream, "{}: {}\r\n", name, value).unwrap();
+                }
+                write!(
+                    stream,
+                    "Content-Length: {}\r\nConnection: close\r\n\r\n",
+                    response.body.len()
+                )
+                .unwrap();
+                stream.write_all(&response.body).unwrap();
+            }
+        });
+        TestServer { url, requests }
+    }
+
+    /// The base URL of the server.
+    pub(crate) fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// A client that makes requests to this server without any delay.
+    pub(crate) fn client(&self) -> ScryfallClient {
+        ScryfallClient::builder()
+            .with_base_url(self.url.clone())
+            .with_rate_limiter(RateLimiter::disabled())
+            .with_retry_policy(RetryPolicy {
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(10),
+                ..Default::default()
+            })
+            .build()
+            .unwrap()
+    }
+
+    /// The requests received so far.
+    pub(crate) fn requests(&self) -> Vec<TestRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn read_request(reader: &mut impl BufRead) -> TestRequest {
+    let mut line = String::new();
+    reader.read_line(&mut line).unwrap();
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    loop {
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+        if line.trim().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap();
+            }
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).unwrap();
+    TestRequest {
+        method,
+        path,
+        body: String::from_utf8(body).unwrap(),
+    }
+}
@@ -10,7 +10,6 @@ use url::ParseError as UrlParseError;
 
 /// The errors that may occur when interacting with the scryfall API.
 #[derive(Debug, thiserror::Error)]
-#[allow(clippy::large_enum_variant)]
 pub enum Error {
     /// Couldn't parse the json returned from scryfall. This error should never
     /// occur. If it does, please
@@ -22,9 +21,18 @@ pub enum Error {
     #[error("Error parsing URL: {0}")]
     UrlParseError(#[from] UrlParseError),
 
+    /// Something went wrong when reading or writing data.
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+
     /// Something went wrong when making the HTTP request.
     #[error("Error making request: {0}")]
-    UreqError(UreqError, String),
+    UreqError(Box<UreqError>, String),
+
+    /// Something went wrong when making an asynchronous HTTP request.
+    #[cfg(feature = "async")]
+    #[error("Error making request: {0}")]
+    ReqwestError(reqwest::Error, String),
 
     /// Scryfall error. Please refer to the [official docs](https://scryfall.com/docs/api/errors).
     #[error("Scryfall error: {0}")]
@@ -34,6 +42,61 @@ pub enum Error {
     #[error("HTTP error: {0} {1}")]
     HttpError(u16, String),
 
+    /// A request kept failing with a transient error until the client's
+    /// [`RetryPolicy`][crate::client::RetryPolicy] ran out of attempts.
+    #[error("Request failed after {attempts} attempts with status {status}: {source}")]
+    RetriesExhausted {
+        /// How many times the request was made.
+        attempts: u32,
+        /// The status code of the last response.
+        status: u16,
+        /// The error caused by the last response.
+        source: Box<Error>,
+    },
+
+    /// A page of a paginated list couldn't be fetched. See
+    /// [`ListIter::fallible`][crate::list::ListIter::fallible].
+    #[error("Error fetching page {page}: {source}")]
+    PageError {
+        /// The number of the page that failed, starting from 1.
+        page: usize,
+        /// The error that occurred when fetching the page.
+        source: Box<Error>,
+    },
+
+    /// A mana cost couldn't be parsed.
+    #[error("Invalid mana cost: {0}")]
+    InvalidManaCost(String),
+
+    /// A type line couldn't be parsed.
+    #[error("Invalid type line: {0}")]
+    InvalidTypeLine(String),
+
+    /// A type line has a subtype that isn't in the catalog of its card type.
+    /// See [`TypeLine::validate`][crate::card::TypeLine::validate].
+    #[error("Unknown subtype {subtype} in type line {type_line}")]
+    UnknownSubtype {
+        /// The subtype that wasn't found.
+        subtype: String,
+        /// The type line it was found in.
+        type_line: String,
+    },
+
+    /// A search query couldn't be parsed. See
+    /// [`Query::parse`][crate::card_searcher::Query::parse].
+    #[error("Invalid query {query}: {reason}")]
+    InvalidQuery {
+        /// The query that was being parsed.
+        query: String,
+        /// What was wrong with it.
+        reason: String,
+    },
+
+    /// A query has params that can't be evaluated without asking Scryfall.
+    /// See [`Matches`][crate::card_searcher::Matches].
+    #[error("Can't evaluate offline: {}", .0.join(" "))]
+    UnsupportedQuery(Vec<String>),
+
     /// Other.
     #[error("{0}")]
     Other(String),
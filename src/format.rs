@@ -1,9 +1,27 @@
 //! The available magic the gathering formats.
+use std::convert::Infallible;
 use std::fmt;
+use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+/// A Magic: The Gathering format.
+///
+/// Formats this crate doesn't know about yet are kept as
+/// [`Format::Other`], so that parsing and deserializing a format never
+/// fails.
+///
+/// # Examples
+/// ```rust
+/// use scryfall::format::Format;
+///
+/// assert_eq!("modern".parse(), Ok(Format::Modern));
+/// assert_eq!(
+///     "newformat".parse(),
+///     Ok(Format::Other("newformat".to_string()))
+/// );
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "lowercase")]
 #[allow(missing_docs)]
 #[non_exhaustive]
@@ -23,6 +41,16 @@ pub enum Format {
     Gladiator,
     Brawl,
     Premodern,
+    Timeless,
+    Explorer,
+    Alchemy,
+    Oathbreaker,
+    StandardBrawl,
+    PauperCommander,
+    Predh,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl fmt::Display for Format {
@@ -47,7 +75,71 @@ impl fmt::Display for Format {
                 Gladiator => "gladiator",
                 Brawl => "brawl",
                 Premodern => "premodern",
+                Timeless => "timeless",
+                Explorer => "explorer",
+                Alchemy => "alchemy",
+                Oathbreaker => "oathbreaker",
+                StandardBrawl => "standardbrawl",
+                PauperCommander => "paupercommander",
+                Predh => "predh",
+                Other(name) => name,
             }
         )
     }
 }
+
+impl FromStr for Format {
+    type Err = Infallible;
+
+    /// Parses a format by the name Scryfall uses. Names that aren't known
+    /// become [`Format::Other`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Format::*;
+        Ok(match s {
+            "standard" => Standard,
+            "modern" => Modern,
+            "legacy" => Legacy,
+            "vintage" => Vintage,
+            "commander" => Commander,
+            "future" => Future,
+            "pauper" => Pauper,
+            "pioneer" => Pioneer,
+            "penny" => Penny,
+            "duel" => Duel,
+            "oldschool" => OldSchool,
+            "historic" => Historic,
+            "gladiator" => Gladiator,
+            "brawl" => Brawl,
+            "premodern" => Premodern,
+            "timeless" => Timeless,
+            "explorer" => Explorer,
+            "alchemy" => Alchemy,
+            "oathbreaker" => Oathbreaker,
+            "standardbrawl" => StandardBrawl,
+            "paupercommander" => PauperCommander,
+            "predh" => Predh,
+            name => Other(name.to_string()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_names_round_trip() {
+        let formats = [
+            Format::Modern,
+            Format::OldSchool,
+            Format::PauperCommander,
+            Format::Other("newformat".to_string()),
+        ];
+        for format in formats.iter() {
+            assert_eq!(format.to_string().parse::<Format>().unwrap(), *format);
+            let json = serde_json::to_string(format).unwrap();
+            assert_eq!(json, format!("\"{}\"", format));
+            assert_eq!(serde_json::from_str::<Format>(&json).unwrap(), *format);
+        }
+    }
+}
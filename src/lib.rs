@@ -37,6 +37,27 @@
 //! assert!(Catalog::card_names().unwrap().data.len() > 0)
 //! ```
 //!
+//! ## Clients
+//! Every request goes through a [`ScryfallClient`](client::ScryfallClient).
+//! The functions shown above use a default client, and each of them has a
+//! `_with` variant that takes a client explicitly, for when you need to
+//! configure timeouts, a user agent, a proxy or a different base URL.
+//!
+//! ## Async
+//! Enabling the `async` feature adds asynchronous versions of the API
+//! functions, with an `_async` suffix, that run on [tokio](https://tokio.rs).
+//! Paginated results are returned as a
+//! `ListStream` instead of a [`ListIter`](list::ListIter).
+//!
+//! ## Extra fields
+//! Scryfall adds fields to its objects from time to time. Enabling the
+//! `extra-fields` feature adds an `extra` map to cards, card faces, related
+//! cards, sets and rulings, holding every field this crate doesn't know
+//! about, so that serializing them again gives back everything Scryfall sent.
+//! The output isn't byte for byte the same though: fields Scryfall left out
+//! that this crate does model are written as `null` or their default value.
+//! Deserializing is somewhat slower with it enabled.
+//!
 //! ## Advanced Search
 //! One of the main features of `scryfall` is its advanced search.
 //! For this the [`card_searcher`] module provides a type safe api
@@ -46,11 +67,13 @@ pub mod bulk;
 pub mod card;
 pub mod card_searcher;
 pub mod catalog;
+pub mod client;
 pub mod error;
 pub mod format;
 pub mod list;
 pub mod ruling;
 pub mod set;
+pub mod symbology;
 pub mod uri;
 mod util;
 
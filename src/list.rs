@@ -4,13 +4,22 @@
 //!
 //! This module also defines [`ListIter`], which can iterate over the contents
 //! of a `List`. If the list is paginated, the `ListIter` will request each page
-//! lazily.
+//! lazily. With the `async` feature enabled, `ListStream` does the same as a
+//! `Stream`.
 
+#[cfg(feature = "async")]
+use std::pin::Pin;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
 use std::vec;
 
+#[cfg(feature = "async")]
+use futures::stream::{self, Stream};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::client::ScryfallClient;
+use crate::error::Error;
 use crate::uri::Uri;
 
 /// A list object.
@@ -30,18 +39,22 @@ pub struct List<T> {
 impl<T: DeserializeOwned> List<T> {
     /// Creates an iterator over all the pages of this list.
     pub fn into_page_iter(self) -> PageIter<T> {
+        self.into_page_iter_with(ScryfallClient::global())
+    }
+
+    /// Creates an iterator over all the pages of this list, that requests the
+    /// following pages using the given client.
+    pub fn into_page_iter_with(self, client: &ScryfallClient) -> PageIter<T> {
         PageIter {
             curr: Some(self),
             page_num: 1,
+            client: client.clone(),
         }
     }
-}
-
-impl<T: DeserializeOwned> IntoIterator for List<T> {
-    type IntoIter = ListIter<T>;
-    type Item = T;
 
-    fn into_iter(self) -> Self::IntoIter {
+    /// Creates an iterator over the items of this list, that requests the
+    /// following pages using the given client.
+    pub fn into_iter_with(self, client: &ScryfallClient) -> ListIter<T> {
         // `has_more` is assumed to be redundant.
         debug_assert!(self.has_more == self.next_page.is_some());
 
@@ -51,18 +64,29 @@ impl<T: DeserializeOwned> IntoIterator for List<T> {
             page_num: 1,
             total: self.total_cards,
             remaining: self.total_cards,
+            client: client.clone(),
         }
     }
 }
 
+impl<T: DeserializeOwned> IntoIterator for List<T> {
+    type IntoIter = ListIter<T>;
+    type Item = T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter_with(ScryfallClient::global())
+    }
+}
+
 /// An iterator that moves objects out of a list.
 ///
 /// This struct is created by the `into_iter` method on `List`.
 ///
 /// Upon reaching the end of a page, further pages will be requested and the
-/// iterator will continue yielding items from those pages. Therefore the
-/// associated `Item` type must be a [`crate::Result`]`<T>` as this request can
-/// fail.
+/// iterator will continue yielding items from those pages. If one of these
+/// requests fails, an error message is logged to stderr and the iteration
+/// stops. Use [`fallible`](#method.fallible) to get an iterator that yields
+/// the error instead.
 #[derive(Debug, Clone)]
 pub struct ListIter<T> {
     inner: vec::IntoIter<T>,
@@ -70,6 +94,7 @@ pub struct ListIter<T> {
     page_num: usize,
     total: Option<usize>,
     remaining: Option<usize>,
+    client: ScryfallClient,
 }
 
 impl<T> ListIter<T> {
@@ -90,6 +115,13 @@ impl<T> ListIter<T> {
     pub fn into_inner(self) -> vec::IntoIter<T> {
         self.inner
     }
+
+    /// Turns this iterator into one that yields a [`crate::Result`]`<T>`, so
+    /// that errors fetching the following pages are reported instead of ending
+    /// the iteration. See [`TryListIter`] for details.
+    pub fn fallible(self) -> TryListIter<T> {
+        TryListIter { inner: self }
+    }
 }
 
 impl<T: DeserializeOwned> ListIter<T> {
@@ -113,7 +145,7 @@ impl<T: DeserializeOwned> ListIter<T> {
     /// ```
     pub fn next_page(&self) -> crate::Result<Option<Self>> {
         if let Some(uri) = self.next_uri.as_ref() {
-            let mut new_iter = uri.fetch_iter()?;
+            let mut new_iter = uri.fetch_iter_with(&self.client)?;
             new_iter.remaining = self.remaining.map(|r| r - self.inner.len());
             new_iter.page_num = self.page_num + 1;
 
@@ -169,11 +201,106 @@ impl<T: DeserializeOwned> Iterator for ListIter<T> {
     }
 }
 
+/// An iterator over the items of a list that reports errors fetching the
+/// following pages.
+///
+/// This struct is created by the [`fallible`](ListIter::fallible) method on
+/// `ListIter`.
+///
+/// When a page fails to load, the iterator yields an [`Error::PageError`]
+/// holding the number of the page and the cause of the failure. The iterator
+/// is left at the position it was in, so calling `next` again requests the
+/// failed page once more, and iteration continues from there if it succeeds.
+///
+/// This means the iterator never ends while a page keeps failing, for example
+/// when Scryfall is down. Adapters that skip errors, like
+/// `filter_map(Result::ok)` or `flatten`, then loop forever. Give up after
+/// some number of errors, or stop at the first one by collecting into a
+/// `Result<Vec<T>, _>`.
+///
+/// # Examples
+/// ```rust,no_run
+/// # use scryfall::error::Error;
+/// # use scryfall::Card;
+/// let mut cards = Card::search("t:goblin").unwrap().fallible();
+/// let mut failures = 0;
+/// while let Some(card) = cards.next() {
+///     match card {
+///         Ok(card) => println!("{}", card.name),
+///         Err(Error::PageError { page, source }) if failures < 3 => {
+///             eprintln!("Retrying page {}: {}", page, source);
+///             failures += 1;
+///         },
+///         Err(e) => {
+///             eprintln!("Giving up: {}", e);
+///             break;
+///         },
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TryListIter<T> {
+    inner: ListIter<T>,
+}
+
+impl<T> TryListIter<T> {
+    /// Turns this iterator back into a [`ListIter`], which stops at the first
+    /// error.
+    pub fn into_inner(self) -> ListIter<T> {
+        self.inner
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for TryListIter<T> {
+    type Item = crate::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(next) = self.inner.inner.next() {
+                self.inner.remaining = self.inner.remaining.map(|r| r - 1);
+                return Some(Ok(next));
+            }
+            match self.inner.next_page() {
+                Ok(Some(new_iter)) => self.inner = new_iter,
+                Ok(None) => return None,
+                Err(e) => {
+                    return Some(Err(Error::PageError {
+                        page: self.inner.page_num + 1,
+                        source: Box::new(e),
+                    }))
+                },
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Errors may be yielded on top of the items.
+        (self.inner.size_hint().0, None)
+    }
+}
+
 /// An iterator over the pages of a list. Before returning each page, the next
-/// page is requested.
+/// page is requested. If that request fails, an error message is logged to
+/// stderr and the iteration stops. Use [`fallible`](#method.fallible) to get an
+/// iterator that yields the error instead.
 pub struct PageIter<T> {
     curr: Option<List<T>>,
     page_num: usize,
+    client: ScryfallClient,
+}
+
+impl<T> PageIter<T> {
+    /// Turns this iterator into one that yields a [`crate::Result`]`<List<T>>`,
+    /// so that errors fetching the following pages are reported instead of
+    /// ending the iteration. See [`TryPageIter`] for details.
+    pub fn fallible(self) -> TryPageIter<T> {
+        TryPageIter {
+            curr: self.curr,
+            next_uri: None,
+            page_num: self.page_num,
+            client: self.client,
+        }
+    }
 }
 
 impl<T: DeserializeOwned> Iterator for PageIter<T> {
@@ -182,7 +309,7 @@ impl<T: DeserializeOwned> Iterator for PageIter<T> {
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(curr) = self.curr.take() {
             self.curr = match &curr.next_page {
-                Some(uri) => match uri.fetch() {
+                Some(uri) => match uri.fetch_with(&self.client) {
                     Ok(page) => {
                         self.page_num += 1;
                         Some(page)
@@ -200,3 +327,221 @@ impl<T: DeserializeOwned> Iterator for PageIter<T> {
         }
     }
 }
+
+/// An iterator over the pages of a list that reports errors fetching the
+/// following pages.
+///
+/// This struct is created by the [`fallible`](PageIter::fallible) method on
+/// `PageIter`. Unlike `PageIter`, each page is only requested when it's asked
+/// for. When a page fails to load, the iterator yields an
+/// [`Error::PageError`], and calling `next` again requests the failed page
+/// once more. Like [`TryListIter`], it doesn't end while that page keeps
+/// failing, so the caller has to decide when to give up.
+pub struct TryPageIter<T> {
+    curr: Option<List<T>>,
+    next_uri: Option<Uri<List<T>>>,
+    page_num: usize,
+    client: ScryfallClient,
+}
+
+impl<T: DeserializeOwned> Iterator for TryPageIter<T> {
+    type Item = crate::Result<List<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page = match self.curr.take() {
+            Some(page) => page,
+            None => {
+                let uri = self.next_uri.as_ref()?;
+                match uri.fetch_with(&self.client) {
+                    Ok(page) => {
+                        self.page_num += 1;
+                        page
+                    },
+                    Err(e) => {
+                        return Some(Err(Error::PageError {
+                            page: self.page_num + 1,
+                            source: Box::new(e),
+                        }))
+                    },
+                }
+            },
+        };
+        self.next_uri = page.next_page.clone();
+        Some(Ok(page))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: DeserializeOwned + Send + 'static> List<T> {
+    /// Creates a stream over the items of this list, that asynchronously
+    /// requests the following pages using the default client.
+    pub fn into_stream(self) -> ListStream<T> {
+        self.into_stream_with(ScryfallClient::global())
+    }
+
+    /// Creates a stream over the items of this list, that asynchronously
+    /// requests the following pages using the given client.
+    pub fn into_stream_with(self, client: &ScryfallClient) -> ListStream<T> {
+        struct State<T> {
+            inner: vec::IntoIter<T>,
+            next_uri: Option<Uri<List<T>>>,
+            client: ScryfallClient,
+        }
+
+        let state = State {
+            inner: self.data.into_iter(),
+            next_uri: self.next_page,
+            client: client.clone(),
+        };
+        let items = stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(item) = state.inner.next() {
+                    return Some((Ok(item), state));
+                }
+                let uri = state.next_uri.take()?;
+                match uri.fetch_async_with(&state.client).await {
+                    Ok(page) => {
+                        state.inner = page.data.into_iter();
+                        state.next_uri = page.next_page;
+                    },
+                    Err(e) => return Some((Err(e), state)),
+                }
+            }
+        });
+
+        ListStream {
+            inner: Box::pin(items),
+            remaining: self.total_cards,
+        }
+    }
+}
+
+/// A stream over the items of a list, the asynchronous counterpart of
+/// [`ListIter`].
+///
+/// Upon reaching the end of a page, the next page is requested and the stream
+/// continues yielding items from it. If a page fails to load, the error is
+/// yielded and the stream ends.
+#[cfg(feature = "async")]
+pub struct ListStream<T> {
+    inner: Pin<Box<dyn Stream<Item = crate::Result<T>> + Send>>,
+    remaining: Option<usize>,
+}
+
+#[cfg(feature = "async")]
+impl<T> Stream for ListStream<T> {
+    type Item = crate::Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let next = self.inner.as_mut().poll_next(cx);
+        if let Poll::Ready(Some(Ok(_))) = next {
+            self.remaining = self.remaining.map(|r| r.saturating_sub(1));
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(len) => (0, Some(len)),
+            None => (0, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::client::test_server::{TestResponse, TestServer};
+    use crate::error::Error;
+    use crate::uri::Uri;
+
+    const NOT_FOUND: &str =
+        r#"{"object":"error","code":"not_found","status":404,"details":"No page found."}"#;
+
+    fn pages(url: &Url) -> Vec<TestResponse> {
+        vec![
+            TestResponse::json(&format!(
+                r#"{{"object":"list","has_more":true,"next_page":"{}page/2","total_cards":4,"data":["a","b"]}}"#,
+                url
+            )),
+            TestResponse::json(
+                r#"{"object":"list","has_more":false,"total_cards":4,"data":["c","d"]}"#,
+            ),
+        ]
+    }
+
+    #[test]
+    fn list_iter_requests_following_pages() {
+        let server = TestServer::from_fn(pages);
+        let client = server.client();
+        let uri = Uri::<super::List<String>>::from(server.url().join("page/1").unwrap());
+
+        let mut iter = uri.fetch_iter_with(&client).unwrap();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), ["a", "b", "c", "d"]);
+        assert_eq!(server.requests()[1].path, "/page/2");
+    }
+
+    #[test]
+    fn try_list_iter_retries_failed_pages() {
+        let server = TestServer::from_fn(|url| {
+            let mut responses = pages(url);
+            responses.insert(1, TestResponse::new(404, NOT_FOUND));
+            responses
+        });
+        let client = server.client();
+        let uri = Uri::<super::List<String>>::from(server.url().join("page/1").unwrap());
+
+        let mut iter = uri.fetch_iter_with(&client).unwrap().fallible();
+        assert_eq!(iter.next().unwrap().unwrap(), "a");
+        assert_eq!(iter.next().unwrap().unwrap(), "b");
+        match iter.next().unwrap().unwrap_err() {
+            Error::PageError { page: 2, source } => {
+                assert!(matches!(*source, Error::ScryfallError(_)))
+            },
+            other => panic!("Wrong error type: {0} {0:?}", other),
+        }
+        let rest = iter.collect::<crate::Result<Vec<_>>>().unwrap();
+        assert_eq!(rest, ["c", "d"]);
+        assert_eq!(server.requests()[2].path, "/page/2");
+    }
+
+    #[test]
+    fn try_page_iter_retries_failed_pages() {
+        let server = TestServer::from_fn(|url| {
+            let mut responses = pages(url);
+            responses.insert(1, TestResponse::new(404, NOT_FOUND));
+            responses
+        });
+        let client = server.client();
+        let uri = Uri::<super::List<String>>::from(server.url().join("page/1").unwrap());
+
+        let mut iter = uri
+            .fetch_with(&client)
+            .unwrap()
+            .into_page_iter_with(&client)
+            .fallible();
+        assert_eq!(iter.next().unwrap().unwrap().data, ["a", "b"]);
+        assert!(matches!(
+            iter.next().unwrap().unwrap_err(),
+            Error::PageError { page: 2, .. }
+        ));
+        assert_eq!(iter.next().unwrap().unwrap().data, ["c", "d"]);
+        assert!(iter.next().is_none());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn list_stream_requests_following_pages() {
+        use futures::TryStreamExt;
+
+        let server = TestServer::from_fn(pages);
+        let client = server.client();
+        let uri = Uri::<super::List<String>>::from(server.url().join("page/1").unwrap());
+
+        let stream = uri.fetch_stream_with(&client).await.unwrap();
+        let items = stream.try_collect::<Vec<_>>().await.unwrap();
+        assert_eq!(items, ["a", "b", "c", "d"]);
+    }
+}
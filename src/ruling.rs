@@ -11,9 +11,10 @@
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+use crate::client::ScryfallClient;
 use crate::list::ListIter;
 use crate::uri::Uri;
-use crate::util::{Uuid, API_RULING, CARDS_URL};
+use crate::util::{Uuid, API_RULING, CARDS_PATH};
 
 /// A ruling object.
 ///
@@ -26,6 +27,10 @@ pub struct Ruling {
     pub source: Source,
     pub published_at: NaiveDate,
     pub comment: String,
+    /// The fields of the ruling that this crate doesn't model.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// The two possible ruling sources
@@ -52,13 +57,20 @@ impl Ruling {
     /// );
     /// ```
     pub fn multiverse_id(id: usize) -> crate::Result<ListIter<Self>> {
+        Ruling::multiverse_id_with(ScryfallClient::global(), id)
+    }
+
+    /// Same as [`multiverse_id`](#method.multiverse_id), but the request is
+    /// made with the given client.
+    pub fn multiverse_id_with(client: &ScryfallClient, id: usize) -> crate::Result<ListIter<Self>> {
         Uri::from(
-            CARDS_URL
+            client
+                .endpoint(CARDS_PATH)?
                 .join("multiverse/")?
                 .join(&format!("{}/", id))?
                 .join(API_RULING)?,
         )
-        .fetch_iter()
+        .fetch_iter_with(client)
     }
 
     /// Returns rulings for a card with the given MTGO ID (also known as the
@@ -75,13 +87,20 @@ impl Ruling {
     /// );
     /// ```
     pub fn mtgo_id(id: usize) -> crate::Result<ListIter<Self>> {
+        Ruling::mtgo_id_with(ScryfallClient::global(), id)
+    }
+
+    /// Same as [`mtgo_id`](#method.mtgo_id), but the request is made with the
+    /// given client.
+    pub fn mtgo_id_with(client: &ScryfallClient, id: usize) -> crate::Result<ListIter<Self>> {
         Uri::from(
-            CARDS_URL
+            client
+                .endpoint(CARDS_PATH)?
                 .join("mtgo/")?
                 .join(&format!("{}/", id))?
                 .join(API_RULING)?,
         )
-        .fetch_iter()
+        .fetch_iter_with(client)
     }
 
     /// Returns rulings for a card with the given Magic: The Gathering Arena ID.
@@ -94,13 +113,20 @@ impl Ruling {
     /// }));
     /// ```
     pub fn arena_id(id: usize) -> crate::Result<ListIter<Self>> {
+        Ruling::arena_id_with(ScryfallClient::global(), id)
+    }
+
+    /// Same as [`arena_id`](#method.arena_id), but the request is made with the
+    /// given client.
+    pub fn arena_id_with(client: &ScryfallClient, id: usize) -> crate::Result<ListIter<Self>> {
         Uri::from(
-            CARDS_URL
+            client
+                .endpoint(CARDS_PATH)?
                 .join("arena/")?
                 .join(&format!("{}/", id))?
                 .join(API_RULING)?,
         )
-        .fetch_iter()
+        .fetch_iter_with(client)
     }
 
     /// Returns a List of rulings for the card with the given set code and
@@ -116,12 +142,23 @@ impl Ruling {
     /// );
     /// ```
     pub fn set_and_number(set: &str, number: u32) -> crate::Result<ListIter<Self>> {
+        Ruling::set_and_number_with(ScryfallClient::global(), set, number)
+    }
+
+    /// Same as [`set_and_number`](#method.set_and_number), but the request is
+    /// made with the given client.
+    pub fn set_and_number_with(
+        client: &ScryfallClient,
+        set: &str,
+        number: u32,
+    ) -> crate::Result<ListIter<Self>> {
         Uri::from(
-            CARDS_URL
+            client
+                .endpoint(CARDS_PATH)?
                 .join(&format!("{}/{}/", set, number))?
                 .join(API_RULING)?,
         )
-        .fetch_iter()
+        .fetch_iter_with(client)
     }
 
     /// Returns a List of rulings for a card with the given Scryfall ID.
@@ -136,6 +173,37 @@ impl Ruling {
     /// );
     /// ```
     pub fn uuid(id: Uuid) -> crate::Result<ListIter<Self>> {
-        Uri::from(CARDS_URL.join(&format!("{}/", id))?.join(API_RULING)?).fetch_iter()
+        Ruling::uuid_with(ScryfallClient::global(), id)
+    }
+
+    /// Same as [`uuid`](#method.uuid), but the request is made with the given
+    /// client.
+    pub fn uuid_with(client: &ScryfallClient, id: Uuid) -> crate::Result<ListIter<Self>> {
+        Uri::from(
+            client
+                .endpoint(CARDS_PATH)?
+                .join(&format!("{}/", id))?
+                .join(API_RULING)?,
+        )
+        .fetch_iter_with(client)
+    }
+}
+
+#[cfg(all(test, feature = "extra-fields"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extra_fields_round_trip() {
+        let json = serde_json::json!({
+            "object": "ruling",
+            "oracle_id": "0004ebd0-dfd6-4276-b4a6-de0003e94237",
+            "source": "wotc",
+            "published_at": "2004-10-04",
+            "comment": "They do not add together.",
+        });
+        let ruling = serde_json::from_value::<Ruling>(json.clone()).unwrap();
+        assert_eq!(ruling.extra["object"], "ruling");
+        assert_eq!(serde_json::to_value(&ruling).unwrap(), json);
     }
 }
@@ -17,9 +17,12 @@ use serde::{Deserialize, Serialize};
 pub use self::set_code::SetCode;
 pub use self::set_type::SetType;
 use crate::card::Card;
+use crate::client::ScryfallClient;
+#[cfg(feature = "async")]
+use crate::list::ListStream;
 use crate::list::{List, ListIter};
 use crate::uri::Uri;
-use crate::util::{Uuid, SETS_URL};
+use crate::util::{Uuid, SETS_PATH};
 
 /// A Set object containing all fields that `scryfall` provides.
 ///
@@ -31,6 +34,7 @@ pub struct Set {
     pub id: Uuid,
     pub code: SetCode,
     pub mtgo_code: Option<String>,
+    pub arena_code: Option<String>,
     pub tcgplayer_id: Option<u64>,
     pub name: String,
     pub set_type: SetType,
@@ -39,12 +43,20 @@ pub struct Set {
     pub block: Option<String>,
     pub parent_set_code: Option<String>,
     pub card_count: usize,
+    pub printed_size: Option<usize>,
     pub digital: bool,
+    #[serde(default)]
     pub foil_only: bool,
+    #[serde(default)]
+    pub nonfoil_only: bool,
     pub scryfall_uri: String,
     pub uri: Uri<Set>,
     pub icon_svg_uri: String,
     pub search_uri: Uri<List<Card>>,
+    /// The fields of the set that this crate doesn't model.
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Set {
@@ -57,9 +69,15 @@ impl Set {
     /// assert!(sets.len() > 0);
     /// ```
     pub fn all() -> crate::Result<ListIter<Set>> {
-        let mut url = SETS_URL.clone();
+        Set::all_with(ScryfallClient::global())
+    }
+
+    /// Same as [`all`](#method.all), but the request is made with the given
+    /// client.
+    pub fn all_with(client: &ScryfallClient) -> crate::Result<ListIter<Set>> {
+        let mut url = client.endpoint(SETS_PATH)?;
         url.query_pairs_mut().append_pair("page", "1");
-        Uri::from(url).fetch_iter()
+        Uri::from(url).fetch_iter_with(client)
     }
 
     /// Returns a `Set` with the given set code.
@@ -72,8 +90,18 @@ impl Set {
     /// assert_eq!(Set::code("mmq").unwrap().name, "Mercadian Masques")
     /// ```
     pub fn code(code: &str) -> crate::Result<Set> {
-        Uri::from(SETS_URL.join(&percent_encode(code.as_bytes(), NON_ALPHANUMERIC).to_string())?)
-            .fetch()
+        Set::code_with(ScryfallClient::global(), code)
+    }
+
+    /// Same as [`code`](#method.code), but the request is made with the given
+    /// client.
+    pub fn code_with(client: &ScryfallClient, code: &str) -> crate::Result<Set> {
+        Uri::from(
+            client
+                .endpoint(SETS_PATH)?
+                .join(&percent_encode(code.as_bytes(), NON_ALPHANUMERIC).to_string())?,
+        )
+        .fetch_with(client)
     }
 
     /// Returns a `Set` with the given `tcgplayer_id`.
@@ -87,12 +115,22 @@ impl Set {
     /// assert_eq!(Set::tcgplayer(1909).unwrap().name, "Amonkhet Invocations")
     /// ```
     pub fn tcgplayer<T: std::fmt::Display>(code: T) -> crate::Result<Set> {
+        Set::tcgplayer_with(ScryfallClient::global(), code)
+    }
+
+    /// Same as [`tcgplayer`](#method.tcgplayer), but the request is made with
+    /// the given client.
+    pub fn tcgplayer_with<T: std::fmt::Display>(
+        client: &ScryfallClient,
+        code: T,
+    ) -> crate::Result<Set> {
         Uri::from(
-            SETS_URL
+            client
+                .endpoint(SETS_PATH)?
                 .join("tcgplayer/")?
                 .join(&percent_encode(code.to_string().as_bytes(), NON_ALPHANUMERIC).to_string())?,
         )
-        .fetch()
+        .fetch_with(client)
     }
 
     /// Returns a Set with the given Scryfall `uuid`.
@@ -108,11 +146,144 @@ impl Set {
     /// )
     /// ```
     pub fn uuid(uuid: Uuid) -> crate::Result<Set> {
-        Uri::from(SETS_URL.join(&uuid.to_string())?).fetch()
+        Set::uuid_with(ScryfallClient::global(), uuid)
+    }
+
+    /// Same as [`uuid`](#method.uuid), but the request is made with the given
+    /// client.
+    pub fn uuid_with(client: &ScryfallClient, uuid: Uuid) -> crate::Result<Set> {
+        Uri::from(client.endpoint(SETS_PATH)?.join(&uuid.to_string())?).fetch_with(client)
     }
 
     /// Returns an iterator over the cards of the set.
     pub fn cards(&self) -> crate::Result<ListIter<Card>> {
         self.search_uri.fetch_iter()
     }
+
+    /// Returns an iterator over the cards of the set, requested with the given
+    /// client.
+    pub fn cards_with(&self, client: &ScryfallClient) -> crate::Result<ListIter<Card>> {
+        self.search_uri.fetch_iter_with(client)
+    }
+}
+
+#[cfg(feature = "async")]
+impl Set {
+    /// Asynchronous version of [`all`](#method.all).
+    pub async fn all_async() -> crate::Result<ListStream<Set>> {
+        Set::all_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`all_async`](#method.all_async), but the request is made with
+    /// the given client.
+    pub async fn all_async_with(client: &ScryfallClient) -> crate::Result<ListStream<Set>> {
+        let mut url = client.endpoint(SETS_PATH)?;
+        url.query_pairs_mut().append_pair("page", "1");
+        Uri::from(url).fetch_stream_with(client).await
+    }
+
+    /// Asynchronous version of [`code`](#method.code).
+    pub async fn code_async(code: &str) -> crate::Result<Set> {
+        Set::code_async_with(ScryfallClient::global(), code).await
+    }
+
+    /// Same as [`code_async`](#method.code_async), but the request is made with
+    /// the given client.
+    pub async fn code_async_with(client: &ScryfallClient, code: &str) -> crate::Result<Set> {
+        Uri::from(
+            client
+                .endpoint(SETS_PATH)?
+                .join(&percent_encode(code.as_bytes(), NON_ALPHANUMERIC).to_string())?,
+        )
+        .fetch_async_with(client)
+        .await
+    }
+
+    /// Asynchronous version of [`tcgplayer`](#method.tcgplayer).
+    pub async fn tcgplayer_async<T: std::fmt::Display>(code: T) -> crate::Result<Set> {
+        Set::tcgplayer_async_with(ScryfallClient::global(), code).await
+    }
+
+    /// Same as [`tcgplayer_async`](#method.tcgplayer_async), but the request is
+    /// made with the given client.
+    pub async fn tcgplayer_async_with<T: std::fmt::Display>(
+        client: &ScryfallClient,
+        code: T,
+    ) -> crate::Result<Set> {
+        Uri::from(
+            client
+                .endpoint(SETS_PATH)?
+                .join("tcgplayer/")?
+                .join(&percent_encode(code.to_string().as_bytes(), NON_ALPHANUMERIC).to_string())?,
+        )
+        .fetch_async_with(client)
+        .await
+    }
+
+    /// Asynchronous version of [`uuid`](#method.uuid).
+    pub async fn uuid_async(uuid: Uuid) -> crate::Result<Set> {
+        Set::uuid_async_with(ScryfallClient::global(), uuid).await
+    }
+
+    /// Same as [`uuid_async`](#method.uuid_async), but the request is made with
+    /// the given client.
+    pub async fn uuid_async_with(client: &ScryfallClient, uuid: Uuid) -> crate::Result<Set> {
+        Uri::from(client.endpoint(SETS_PATH)?.join(&uuid.to_string())?)
+            .fetch_async_with(client)
+            .await
+    }
+
+    /// Asynchronous version of [`cards`](#method.cards).
+    pub async fn cards_async(&self) -> crate::Result<ListStream<Card>> {
+        self.cards_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`cards_async`](#method.cards_async), but the request is made
+    /// with the given client.
+    pub async fn cards_async_with(
+        &self,
+        client: &ScryfallClient,
+    ) -> crate::Result<ListStream<Card>> {
+        self.search_uri.fetch_stream_with(client).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODERN_HORIZONS_2: &str = include_str!("../tests/fixtures/modern_horizons_2.json");
+
+    #[test]
+    fn deserialize_set() {
+        let set = serde_json::from_str::<Set>(MODERN_HORIZONS_2).unwrap();
+        assert_eq!(set.code.to_string(), "mh2");
+        assert_eq!(set.arena_code.as_deref(), Some("mh2"));
+        assert_eq!(set.set_type, SetType::DraftInnovation);
+        assert_eq!(set.card_count, 505);
+        assert_eq!(set.printed_size, Some(303));
+        assert!(!set.nonfoil_only && !set.foil_only);
+
+        let mut old = serde_json::from_str::<serde_json::Value>(MODERN_HORIZONS_2).unwrap();
+        for key in &["arena_code", "printed_size", "nonfoil_only", "foil_only"] {
+            old.as_object_mut().unwrap().remove(*key);
+        }
+        let old = serde_json::from_value::<Set>(old).unwrap();
+        assert_eq!(old.arena_code, None);
+        assert_eq!(old.printed_size, None);
+        assert_eq!(old.name, set.name);
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn extra_fields_round_trip() {
+        let mut json = serde_json::from_str::<serde_json::Value>(MODERN_HORIZONS_2).unwrap();
+        json["subsets"] = serde_json::json!(["mh2-retro"]);
+        let set = serde_json::from_value::<Set>(json.clone()).unwrap();
+        assert_eq!(set.extra["subsets"][0], "mh2-retro");
+        let round_trip = serde_json::to_value(&set).unwrap();
+        for (key, value) in json.as_object().unwrap() {
+            assert_eq!(&round_trip[key], value);
+        }
+    }
 }
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 /// Scryfall provides an overall categorization for each Set in the set_type
 /// property.
-#[derive(Serialize, Deserialize, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum SetType {
@@ -50,4 +50,73 @@ pub enum SetType {
     /// A set made up of gold-bordered, oversize, or trophy cards that are not
     /// legal
     Memorabilia,
+    /// A set of cards that only exist in Arena's Alchemy formats
+    Alchemy,
+    /// A set of Commander-oriented gift boxes, like Commander's Arsenal
+    Arsenal,
+    /// A set of cards for a minigame, like Jumpstart front cards
+    Minigame,
+    /// A set made up of cards reprinted for the Eternal formats
+    Eternal,
+    /// A value this crate doesn't know about, as Scryfall names it.
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl std::fmt::Display for SetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use SetType::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Core => "core",
+                Expansion => "expansion",
+                Masters => "masters",
+                Masterpiece => "masterpiece",
+                FromTheVault => "from_the_vault",
+                Spellbook => "spellbook",
+                PremiumDeck => "premium_deck",
+                DuelDeck => "duel_deck",
+                DraftInnovation => "draft_innovation",
+                TreasureChest => "treasure_chest",
+                Commander => "commander",
+                Planechase => "planechase",
+                Archenemy => "archenemy",
+                Vanguard => "vanguard",
+                Funny => "funny",
+                Starter => "starter",
+                GiftBox => "box",
+                Promo => "promo",
+                Token => "token",
+                Memorabilia => "memorabilia",
+                Alchemy => "alchemy",
+                Arsenal => "arsenal",
+                Minigame => "minigame",
+                Eternal => "eternal",
+                Other(name) => name,
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unseen_set_types() {
+        for (json, set_type) in [
+            (r#""box""#, SetType::GiftBox),
+            (r#""draft_innovation""#, SetType::DraftInnovation),
+            (r#""minigame""#, SetType::Minigame),
+            (r#""showcase""#, SetType::Other("showcase".to_string())),
+        ]
+        .iter()
+        {
+            assert_eq!(serde_json::from_str::<SetType>(json).unwrap(), *set_type);
+            assert_eq!(serde_json::to_string(set_type).unwrap(), *json);
+            assert_eq!(format!("\"{}\"", set_type), *json);
+        }
+    }
 }
@@ -0,0 +1,107 @@
+//! Card symbols are the symbols that appear in mana costs and card text, like
+//! `{T}`, `{W/U}` or `{2/B}`. Scryfall provides descriptions of each of them,
+//! and can parse a mana cost written in its notation.
+//!
+//! Mana costs can also be parsed locally into a [`ManaCost`], whose symbols are
+//! described by [`ManaSymbol`].
+//!
+//! See also: [Official Docs](https://scryfall.com/docs/api/card-symbols)
+mod mana_cost;
+mod mana_symbol;
+
+use serde::{Deserialize, Serialize};
+
+pub use self::mana_cost::ManaCost;
+pub use self::mana_symbol::ManaSymbol;
+use crate::card::Color;
+use crate::client::ScryfallClient;
+use crate::list::ListIter;
+#[cfg(feature = "async")]
+use crate::list::ListStream;
+use crate::uri::Uri;
+use crate::util::SYMBOLOGY_PATH;
+
+/// A card symbol object.
+///
+/// For documentation on its fields refer to the
+/// [card symbol object](https://scryfall.com/docs/api/card-symbols) on the
+/// official site.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct CardSymbol {
+    pub symbol: String,
+    pub svg_uri: Option<String>,
+    pub loose_variant: Option<String>,
+    pub english: String,
+    pub transposable: bool,
+    pub represents_mana: bool,
+    pub cmc: Option<f32>,
+    pub appears_in_mana_costs: bool,
+    pub funny: bool,
+    pub colors: Vec<Color>,
+    pub gatherer_alternates: Option<Vec<String>>,
+}
+
+impl CardSymbol {
+    /// Returns a [`ListIter`] of all the card symbols.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::symbology::CardSymbol;
+    /// let tap = CardSymbol::all()
+    ///     .unwrap()
+    ///     .find(|symbol| symbol.symbol == "{T}")
+    ///     .unwrap();
+    /// assert_eq!(tap.english, "tap this permanent");
+    /// ```
+    pub fn all() -> crate::Result<ListIter<CardSymbol>> {
+        CardSymbol::all_with(ScryfallClient::global())
+    }
+
+    /// Same as [`all`](#method.all), but the request is made with the given
+    /// client.
+    pub fn all_with(client: &ScryfallClient) -> crate::Result<ListIter<CardSymbol>> {
+        Uri::from(client.endpoint(SYMBOLOGY_PATH)?).fetch_iter_with(client)
+    }
+}
+
+#[cfg(feature = "async")]
+impl CardSymbol {
+    /// Asynchronous version of [`all`](#method.all).
+    pub async fn all_async() -> crate::Result<ListStream<CardSymbol>> {
+        CardSymbol::all_async_with(ScryfallClient::global()).await
+    }
+
+    /// Same as [`all_async`](#method.all_async), but the request is made with
+    /// the given client.
+    pub async fn all_async_with(client: &ScryfallClient) -> crate::Result<ListStream<CardSymbol>> {
+        Uri::from(client.endpoint(SYMBOLOGY_PATH)?)
+            .fetch_stream_with(client)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::{TestResponse, TestServer};
+
+    #[test]
+    fn all_symbols() {
+        let server = TestServer::new(vec![TestResponse::json(
+            r#"{"object":"list","has_more":false,"data":[
+                {"object":"card_symbol","symbol":"{T}","svg_uri":"https://svgs.scryfall.io/card-symbols/T.svg","loose_variant":null,"english":"tap this permanent","transposable":false,"represents_mana":false,"appears_in_mana_costs":false,"mana_value":0.0,"cmc":0.0,"funny":false,"colors":[],"hybrid":false,"phyrexian":false,"gatherer_alternates":["ocT","oT"]},
+                {"object":"card_symbol","symbol":"{W/U}","svg_uri":"https://svgs.scryfall.io/card-symbols/WU.svg","loose_variant":null,"english":"one white or blue mana","transposable":true,"represents_mana":true,"appears_in_mana_costs":true,"mana_value":1.0,"cmc":1.0,"funny":false,"colors":["W","U"],"hybrid":true,"phyrexian":false,"gatherer_alternates":["(u/w)"]}
+            ]}"#,
+        )]);
+        let symbols = CardSymbol::all_with(&server.client())
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(symbols.len(), 2);
+        assert_eq!(symbols[0].symbol, "{T}");
+        assert!(!symbols[0].represents_mana);
+        assert_eq!(symbols[1].colors, [Color::White, Color::Blue]);
+        assert_eq!(symbols[1].cmc, Some(1.0));
+        assert_eq!(server.requests()[0].path, "/symbology/");
+    }
+}
@@ -0,0 +1,272 @@
+//! This module defines a mana cost, which can be parsed locally or by
+//! Scryfall.
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use super::ManaSymbol;
+use crate::card::Color;
+use crate::client::ScryfallClient;
+use crate::error::Error;
+use crate::uri::Uri;
+use crate::util::SYMBOLOGY_PATH;
+
+/// A mana cost, like `{2}{W/U}{B/P}`.
+///
+/// A mana cost is either parsed locally, with [`str::parse`], or by Scryfall,
+/// with [`ManaCost::parse_remote`]. Either way, it holds the symbols of the
+/// cost, and the mana value and colors are computed from them. Its
+/// [`Display`](fmt::Display) gives the cost in Scryfall's notation.
+///
+/// It is serialized like Scryfall's
+/// [manacost object](https://scryfall.com/docs/api/card-symbols/parse-mana).
+///
+/// # Examples
+/// ```rust
+/// use scryfall::card::Color;
+/// use scryfall::symbology::{ManaCost, ManaSymbol};
+///
+/// let cost = "{2}{w/u}{B/P}".parse::<ManaCost>().unwrap();
+/// assert_eq!(cost.to_string(), "{2}{W/U}{B/P}");
+/// assert_eq!(cost.cmc(), 4.0);
+/// assert_eq!(cost.colors(), [Color::White, Color::Blue, Color::Black]);
+/// assert!(cost.is_multicolored());
+/// assert_eq!(cost.devotion(Color::Blue), 1);
+/// assert_eq!(cost.symbols()[2], ManaSymbol::Phyrexian(Color::Black));
+/// ```
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+#[serde(try_from = "RawManaCost", into = "RawManaCost")]
+pub struct ManaCost {
+    symbols: Vec<ManaSymbol>,
+}
+
+/// A mana cost as Scryfall writes it. Only `cost` is read, the other fields
+/// are computed from it.
+#[derive(Serialize, Deserialize)]
+struct RawManaCost {
+    cost: String,
+    cmc: f32,
+    colors: Vec<Color>,
+    colorless: bool,
+    monocolored: bool,
+    multicolored: bool,
+}
+
+impl ManaCost {
+    /// Creates a mana cost made of the given symbols, in order.
+    pub fn from_symbols(symbols: impl IntoIterator<Item = ManaSymbol>) -> ManaCost {
+        ManaCost {
+            symbols: symbols.into_iter().collect(),
+        }
+    }
+
+    /// The symbols of this cost, in order.
+    pub fn symbols(&self) -> &[ManaSymbol] {
+        &self.symbols
+    }
+
+    /// Turns this cost into its symbols, in order.
+    pub fn into_symbols(self) -> Vec<ManaSymbol> {
+        self.symbols
+    }
+
+    /// The mana value of this cost.
+    pub fn cmc(&self) -> f32 {
+        self.symbols.iter().map(ManaSymbol::mana_value).sum()
+    }
+
+    /// The colors of the symbols of this cost, in WUBRG order.
+    pub fn colors(&self) -> Vec<Color> {
+        [
+            Color::White,
+            Color::Blue,
+            Color::Black,
+            Color::Red,
+            Color::Green,
+        ]
+        .iter()
+        .copied()
+        .filter(|&color| self.devotion(color) > 0)
+        .collect()
+    }
+
+    /// Checks whether none of the symbols of this cost have a color.
+    pub fn is_colorless(&self) -> bool {
+        self.colors().is_empty()
+    }
+
+    /// Checks whether the symbols of this cost have exactly one color.
+    pub fn is_monocolored(&self) -> bool {
+        self.colors().len() == 1
+    }
+
+    /// Checks whether the symbols of this cost have more than one color.
+    pub fn is_multicolored(&self) -> bool {
+        self.colors().len() > 1
+    }
+
+    /// The number of symbols of this cost that are of the given color. Hybrid
+    /// symbols count towards each of their colors.
+    pub fn devotion(&self, color: Color) -> usize {
+        self.symbols
+            .iter()
+            .filter(|symbol| symbol.is(color))
+            .count()
+    }
+
+    /// Asks Scryfall to parse a mana cost. The cost is normalized, so that
+    /// loosely written costs like `RUx` become `{X}{U}{R}`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use scryfall::card::Color;
+    /// use scryfall::symbology::ManaCost;
+    /// let cost = ManaCost::parse_remote("RUx").unwrap();
+    /// assert_eq!(cost.to_string(), "{X}{U}{R}");
+    /// assert_eq!(cost.cmc(), 2.0);
+    /// assert_eq!(cost.colors(), [Color::Blue, Color::Red]);
+    /// assert!(cost.is_multicolored());
+    /// ```
+    pub fn parse_remote(cost: &str) -> crate::Result<ManaCost> {
+        ManaCost::parse_remote_with(ScryfallClient::global(), cost)
+    }
+
+    /// Same as [`parse_remote`](#method.parse_remote), but the request is made
+    /// with the given client.
+    pub fn parse_remote_with(client: &ScryfallClient, cost: &str) -> crate::Result<ManaCost> {
+        let mut url = client.endpoint(SYMBOLOGY_PATH)?.join("parse-mana")?;
+        url.query_pairs_mut().append_pair("cost", cost);
+        Uri::from(url).fetch_with(client)
+    }
+}
+
+#[cfg(feature = "async")]
+impl ManaCost {
+    /// Asynchronous version of [`parse_remote`](#method.parse_remote).
+    pub async fn parse_remote_async(cost: &str) -> crate::Result<ManaCost> {
+        ManaCost::parse_remote_async_with(ScryfallClient::global(), cost).await
+    }
+
+    /// Same as [`parse_remote_async`](#method.parse_remote_async), but the
+    /// request is made with the given client.
+    pub async fn parse_remote_async_with(
+        client: &ScryfallClient,
+        cost: &str,
+    ) -> crate::Result<ManaCost> {
+        let mut url = client.endpoint(SYMBOLOGY_PATH)?.join("parse-mana")?;
+        url.query_pairs_mut().append_pair("cost", cost);
+        Uri::from(url).fetch_async_with(client).await
+    }
+}
+
+impl FromStr for ManaCost {
+    type Err = Error;
+
+    /// Parses a mana cost written in Scryfall's notation, where each symbol is
+    /// between braces. Unknown symbols are kept as [`ManaSymbol::Other`], but
+    /// anything outside of braces is an error.
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let invalid = || Error::InvalidManaCost(s.to_string());
+        let mut symbols = Vec::new();
+        let mut rest = s.trim();
+        while !rest.is_empty() {
+            let symbol = rest.strip_prefix('{').ok_or_else(invalid)?;
+            let end = symbol.find('}').ok_or_else(invalid)?;
+            if end == 0 {
+                return Err(invalid());
+            }
+            symbols.push(ManaSymbol::from_inner(&symbol[..end]));
+            rest = symbol[end + 1..].trim_start();
+        }
+        Ok(ManaCost::from_symbols(symbols))
+    }
+}
+
+impl fmt::Display for ManaCost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for symbol in &self.symbols {
+            write!(f, "{}", symbol)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<ManaCost> for RawManaCost {
+    fn from(cost: ManaCost) -> Self {
+        RawManaCost {
+            cost: cost.to_string(),
+            cmc: cost.cmc(),
+            colors: cost.colors(),
+            colorless: cost.is_colorless(),
+            monocolored: cost.is_monocolored(),
+            multicolored: cost.is_multicolored(),
+        }
+    }
+}
+
+impl TryFrom<RawManaCost> for ManaCost {
+    type Error = Error;
+
+    fn try_from(raw: RawManaCost) -> crate::Result<Self> {
+        raw.cost.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::{TestResponse, TestServer};
+
+    #[test]
+    fn parse_locally() {
+        let cost = "{X}{2}{G}{G/U}{2/G}".parse::<ManaCost>().unwrap();
+        assert_eq!(cost.cmc(), 6.0);
+        assert_eq!(cost.colors(), [Color::Blue, Color::Green]);
+        assert_eq!(cost.devotion(Color::Green), 3);
+        assert_eq!(cost.devotion(Color::Blue), 1);
+        assert_eq!(cost.to_string(), "{X}{2}{G}{G/U}{2/G}");
+
+        let cost = "{C}{S}{HR}".parse::<ManaCost>().unwrap();
+        assert_eq!(cost.cmc(), 2.5);
+        assert!(cost.is_monocolored());
+
+        let cost = "".parse::<ManaCost>().unwrap();
+        assert!(cost.is_colorless() && !cost.is_monocolored() && !cost.is_multicolored());
+        assert_eq!(cost.cmc(), 0.0);
+        assert!(cost.symbols().is_empty());
+
+        for invalid in &["2W", "{2}{W", "{}", "{1} // {2}"] {
+            assert!(
+                matches!(invalid.parse::<ManaCost>(), Err(Error::InvalidManaCost(_))),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn parse_remote() {
+        let server = TestServer::new(vec![TestResponse::json(
+            r#"{"object":"mana_cost","cost":"{X}{U}{R}","colors":["U","R"],"cmc":2.0,"colorless":false,"monocolored":false,"multicolored":true}"#,
+        )]);
+        let cost = ManaCost::parse_remote_with(&server.client(), "RUx").unwrap();
+        assert_eq!(cost.to_string(), "{X}{U}{R}");
+        assert_eq!(cost.colors(), [Color::Blue, Color::Red]);
+        assert!(cost.is_multicolored() && !cost.is_monocolored() && !cost.is_colorless());
+        assert_eq!(server.requests()[0].path, "/symbology/parse-mana?cost=RUx");
+        assert_eq!(cost, "{X}{U}{R}".parse().unwrap());
+        assert_eq!(
+            serde_json::to_value(&cost).unwrap(),
+            serde_json::json!({
+                "cost": "{X}{U}{R}",
+                "cmc": 2.0,
+                "colors": ["U", "R"],
+                "colorless": false,
+                "monocolored": false,
+                "multicolored": true,
+            })
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! This module defines the symbols that make up a mana cost.
+use std::fmt;
+
+use crate::card::Color;
+
+/// A symbol that can appear in a mana cost, like `{2}`, `{W/U}` or `{B/P}`.
+///
+/// Hybrid symbols are always stored with their colors in the order Scryfall
+/// writes them, so `{U/W}` and `{W/U}` are the same symbol.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ManaSymbol {
+    /// Generic mana, like `{2}`.
+    Generic(u32),
+    /// One mana of a color, like `{W}`.
+    Colored(Color),
+    /// One colorless mana, `{C}`.
+    Colorless,
+    /// One mana from a snow source, `{S}`.
+    Snow,
+    /// A variable amount of generic mana, `{X}`.
+    X,
+    /// A second variable amount of generic mana, `{Y}`.
+    Y,
+    /// A third variable amount of generic mana, `{Z}`.
+    Z,
+    /// One mana of either of two colors, like `{W/U}`.
+    Hybrid(Color, Color),
+    /// One mana of a color or two generic mana, like `{2/W}`.
+    TwoHybrid(Color),
+    /// One colorless mana or one mana of a color, like `{C/W}`.
+    ColorlessHybrid(Color),
+    /// One mana of a color or 2 life, like `{W/P}`.
+    Phyrexian(Color),
+    /// One mana of either of two colors or 2 life, like `{W/U/P}`.
+    HybridPhyrexian(Color, Color),
+    /// Half of a mana of a color, like `{HW}`, or half of a generic mana,
+    /// `{½}`.
+    Half(Option<Color>),
+    /// An infinite amount of generic mana, `{∞}`.
+    Infinity,
+    /// A symbol this crate doesn't know about, without its braces.
+    Other(String),
+}
+
+impl ManaSymbol {
+    /// Parses the inside of a symbol, without its braces. Symbols that aren't
+    /// recognized become [`ManaSymbol::Other`].
+    pub(crate) fn from_inner(inner: &str) -> ManaSymbol {
+        use ManaSymbol::*;
+        let upper = inner.to_uppercase();
+        let parts = upper.split('/').collect::<Vec<_>>();
+        let symbol = match parts.as_slice() {
+            ["C"] => Some(Colorless),
+            ["S"] => Some(Snow),
+            ["X"] => Some(X),
+            ["Y"] => Some(Y),
+            ["Z"] => Some(Z),
+            ["½"] => Some(Half(None)),
+            ["∞"] => Some(Infinity),
+            [single] => match single.strip_prefix('H') {
+                Some(color) if !color.is_empty() => color_of(color).map(|c| Half(Some(c))),
+                _ => color_of(single)
+                    .map(Colored)
+                    .or_else(|| single.parse().ok().map(Generic)),
+            },
+            ["2", color] => color_of(color).map(TwoHybrid),
+            ["C", color] => color_of(color).map(ColorlessHybrid),
+            [color, "P"] => color_of(color).map(Phyrexian),
+            [a, b] => hybrid_colors(a, b).map(|(a, b)| Hybrid(a, b)),
+            [a, b, "P"] => hybrid_colors(a, b).map(|(a, b)| HybridPhyrexian(a, b)),
+            _ => None,
+        };
+        symbol.unwrap_or_else(|| Other(inner.to_string()))
+    }
+
+    /// The amount this symbol adds to the mana value of a cost.
+    pub fn mana_value(&self) -> f32 {
+        use ManaSymbol::*;
+        match self {
+            Generic(n) => *n as f32,
+            X | Y | Z | Other(_) => 0.0,
+            TwoHybrid(_) => 2.0,
+            Half(_) => 0.5,
+            Infinity => f32::INFINITY,
+            _ => 1.0,
+        }
+    }
+
+    /// The colors of this symbol.
+    pub fn colors(&self) -> Vec<Color> {
+        use ManaSymbol::*;
+        match self {
+            Colored(c) | TwoHybrid(c) | ColorlessHybrid(c) | Phyrexian(c) | Half(Some(c)) => {
+                vec![*c]
+            },
+            Hybrid(a, b) | HybridPhyrexian(a, b) => vec![*a, *b],
+            _ => vec![],
+        }
+    }
+
+    /// Checks if this symbol is of the given color. Hybrid symbols are of each
+    /// of their colors.
+    pub fn is(&self, color: Color) -> bool {
+        self.colors().contains(&color)
+    }
+}
+
+impl fmt::Display for ManaSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ManaSymbol::*;
+        match self {
+            Generic(n) => write!(f, "{{{}}}", n),
+            Colored(c) => write!(f, "{{{}}}", letter(*c)),
+            Colorless => write!(f, "{{C}}"),
+            Snow => write!(f, "{{S}}"),
+            X => write!(f, "{{X}}"),
+            Y => write!(f, "{{Y}}"),
+            Z => write!(f, "{{Z}}"),
+            Hybrid(a, b) => write!(f, "{{{}/{}}}", letter(*a), letter(*b)),
+            TwoHybrid(c) => write!(f, "{{2/{}}}", letter(*c)),
+            ColorlessHybrid(c) => write!(f, "{{C/{}}}", letter(*c)),
+            Phyrexian(c) => write!(f, "{{{}/P}}", letter(*c)),
+            HybridPhyrexian(a, b) => write!(f, "{{{}/{}/P}}", letter(*a), letter(*b)),
+            Half(Some(c)) => write!(f, "{{H{}}}", letter(*c)),
+            Half(None) => write!(f, "{{½}}"),
+            Infinity => write!(f, "{{∞}}"),
+            Other(s) => write!(f, "{{{}}}", s),
+        }
+    }
+}
+
+fn color_of(letter: &str) -> Option<Color> {
+    match letter {
+        "W" => Some(Color::White),
+        "U" => Some(Color::Blue),
+        "B" => Some(Color::Black),
+        "R" => Some(Color::Red),
+        "G" => Some(Color::Green),
+        _ => None,
+    }
+}
+
+fn letter(color: Color) -> char {
+    match color {
+        Color::White => 'W',
+        Color::Blue => 'U',
+        Color::Black => 'B',
+        Color::Red => 'R',
+        Color::Green => 'G',
+    }
+}
+
+/// The colors of a hybrid symbol, in the order Scryfall writes them: each
+/// color is followed by one of the next two colors around the color wheel.
+fn hybrid_colors(a: &str, b: &str) -> Option<(Color, Color)> {
+    let (a, b) = (color_of(a)?, color_of(b)?);
+    match (b as u8 + 5 - a as u8) % 5 {
+        1 | 2 => Some((a, b)),
+        3 | 4 => Some((b, a)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_format_symbols() {
+        use ManaSymbol::*;
+        let symbols = [
+            ("12", Generic(12), "{12}"),
+            ("r", Colored(Color::Red), "{R}"),
+            ("C", Colorless, "{C}"),
+            ("S", Snow, "{S}"),
+            ("X", X, "{X}"),
+            ("W/U", Hybrid(Color::White, Color::Blue), "{W/U}"),
+            ("W/G", Hybrid(Color::Green, Color::White), "{G/W}"),
+            ("2/B", TwoHybrid(Color::Black), "{2/B}"),
+            ("C/R", ColorlessHybrid(Color::Red), "{C/R}"),
+            ("G/P", Phyrexian(Color::Green), "{G/P}"),
+            (
+                "U/B/P",
+                HybridPhyrexian(Color::Blue, Color::Black),
+                "{U/B/P}",
+            ),
+            ("HW", Half(Some(Color::White)), "{HW}"),
+            ("½", Half(None), "{½}"),
+            ("∞", Infinity, "{∞}"),
+            ("TK", Other("TK".to_string()), "{TK}"),
+            ("W/W", Other("W/W".to_string()), "{W/W}"),
+        ];
+        for (inner, symbol, formatted) in symbols.iter() {
+            assert_eq!(ManaSymbol::from_inner(inner), *symbol);
+            assert_eq!(symbol.to_string(), *formatted);
+        }
+    }
+
+    #[test]
+    fn symbol_values_and_colors() {
+        assert_eq!(ManaSymbol::Generic(3).mana_value(), 3.0);
+        assert_eq!(ManaSymbol::TwoHybrid(Color::Red).mana_value(), 2.0);
+        assert_eq!(ManaSymbol::Half(None).mana_value(), 0.5);
+        assert_eq!(ManaSymbol::X.mana_value(), 0.0);
+        assert!(ManaSymbol::Hybrid(Color::White, Color::Blue).is(Color::Blue));
+        assert!(ManaSymbol::Colorless.colors().is_empty());
+    }
+}
@@ -7,27 +7,39 @@ use std::marker::PhantomData;
 
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use ureq::Agent;
 use url::Url;
 
-use crate::error::Error;
+use crate::client::ScryfallClient;
+#[cfg(feature = "async")]
+use crate::list::ListStream;
 use crate::list::{List, ListIter};
 
-thread_local!(static CLIENT: Agent = Agent::new());
-
 /// An unresolved URI returned by the Scryfall API, or generated by this crate.
 ///
 /// The `fetch` method handles requesting the resource from the API endpoint,
 /// and deserializing it into a `T` object. If the type parameter is
 /// [`List`][crate::list::List]`<_>`, then additional method `fetch_iter` is
 /// available, producing an iterator over all the items in a paged collection.
-#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Hash, Debug)]
+///
+/// With the `async` feature enabled, `fetch_async` and `fetch_stream` do the
+/// same without blocking the current thread.
+#[derive(Serialize, Deserialize, Eq, PartialEq, Hash, Debug)]
 #[serde(transparent)]
 pub struct Uri<T> {
     url: Url,
     _marker: PhantomData<fn() -> T>,
 }
 
+// Implemented by hand, since deriving it would require `T: Clone`.
+impl<T> Clone for Uri<T> {
+    fn clone(&self) -> Self {
+        Uri {
+            url: self.url.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
 // TODO(msmorgan): This should be `TryFrom` since it's fallible.
 impl<T: DeserializeOwned> From<&str> for Uri<T> {
     fn from(url: &str) -> Self {
@@ -57,17 +69,13 @@ impl<T: DeserializeOwned> Uri<T> {
     /// assert_eq!(bolt.mana_cost, Some("{R}".to_string()));
     /// ```
     pub fn fetch(&self) -> crate::Result<T> {
-        let response = CLIENT.with(|client| client.request_url("GET", &self.url).call());
-        match response {
-            Ok(response) => match response.status() {
-                200..=299 => Ok(serde_json::from_reader(response.into_reader())?),
-                status => Err(Error::HttpError(status, response.status_text().to_string())),
-            },
-            Err(ureq::Error::Status(400..=499, response)) => Err(Error::ScryfallError(
-                serde_json::from_reader(response.into_reader())?,
-            )),
-            Err(error) => Err(Error::UreqError(error, self.url.to_string())),
-        }
+        self.fetch_with(ScryfallClient::global())
+    }
+
+    /// Fetches a resource from the Scryfall API using the given client, and
+    /// deserializes it into a type `T`.
+    pub fn fetch_with(&self, client: &ScryfallClient) -> crate::Result<T> {
+        client.fetch(&self.url)
     }
 }
 
@@ -77,6 +85,7 @@ impl<T: DeserializeOwned> Uri<List<T>> {
     /// that the following pages will not fail to fetch because the URLs are
     /// generated by the Scryfall API. If a following page fails, an error
     /// message is logged to stderr and the iterator will only return `None`.
+    /// Use [`ListIter::fallible`] to be notified of such errors instead.
     ///
     /// # Example
     /// ```rust
@@ -92,7 +101,14 @@ impl<T: DeserializeOwned> Uri<List<T>> {
     /// );
     /// ```
     pub fn fetch_iter(&self) -> crate::Result<ListIter<T>> {
-        Ok(self.fetch()?.into_iter())
+        self.fetch_iter_with(ScryfallClient::global())
+    }
+
+    /// Lazily iterate over items from all pages of a list, using the given
+    /// client for this and every following page. See
+    /// [`fetch_iter`](#method.fetch_iter) for details.
+    pub fn fetch_iter_with(&self, client: &ScryfallClient) -> crate::Result<ListIter<T>> {
+        Ok(self.fetch_with(client)?.into_iter_with(client))
     }
 
     /// Eagerly fetch items from all pages of a list. If any of the pages fail
@@ -108,12 +124,104 @@ impl<T: DeserializeOwned> Uri<List<T>> {
     /// assert_eq!(uri.fetch_all().unwrap().len(), 76);
     /// ```
     pub fn fetch_all(&self) -> crate::Result<Vec<T>> {
+        self.fetch_all_with(ScryfallClient::global())
+    }
+
+    /// Eagerly fetch items from all pages of a list using the given client. If
+    /// any of the pages fail to load, returns an error.
+    pub fn fetch_all_with(&self, client: &ScryfallClient) -> crate::Result<Vec<T>> {
+        let mut items = vec![];
+        let mut next_page = Some(self.fetch_with(client)?);
+        while let Some(page) = next_page {
+            items.extend(page.data);
+            next_page = match page.next_page {
+                Some(uri) => Some(uri.fetch_with(client)?),
+                None => None,
+            };
+        }
+        Ok(items)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: DeserializeOwned> Uri<T> {
+    /// Asynchronously fetches a resource from the Scryfall API and
+    /// deserializes it into a type `T`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use scryfall::card::Card;
+    /// # use scryfall::uri::Uri;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let uri = Uri::<Card>::from("https://api.scryfall.com/cards/named?exact=Lightning+Bolt");
+    /// let bolt = uri.fetch_async().await.unwrap();
+    /// assert_eq!(bolt.mana_cost, Some("{R}".to_string()));
+    /// # }
+    /// ```
+    pub async fn fetch_async(&self) -> crate::Result<T> {
+        self.fetch_async_with(ScryfallClient::global()).await
+    }
+
+    /// Asynchronously fetches a resource from the Scryfall API using the given
+    /// client, and deserializes it into a type `T`.
+    pub async fn fetch_async_with(&self, client: &ScryfallClient) -> crate::Result<T> {
+        client.fetch_async(&self.url).await
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: DeserializeOwned + Send + 'static> Uri<List<T>> {
+    /// Asynchronously fetches the first page of a list, returning a stream
+    /// over the items of all its pages. Following pages are only requested
+    /// once the previous page has been exhausted.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use futures::StreamExt;
+    /// # use scryfall::Card;
+    /// # use scryfall::list::List;
+    /// # use scryfall::uri::Uri;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let uri = Uri::<List<Card>>::from("https://api.scryfall.com/cards/search?q=zurgo");
+    /// let mut cards = uri.fetch_stream().await.unwrap();
+    /// let mut found = false;
+    /// while let Some(card) = cards.next().await {
+    ///     found |= card.unwrap().name.contains("Bellstriker");
+    /// }
+    /// assert!(found);
+    /// # }
+    /// ```
+    pub async fn fetch_stream(&self) -> crate::Result<ListStream<T>> {
+        self.fetch_stream_with(ScryfallClient::global()).await
+    }
+
+    /// Asynchronously fetches the first page of a list using the given client,
+    /// returning a stream over the items of all its pages. See
+    /// [`fetch_stream`](#method.fetch_stream) for details.
+    pub async fn fetch_stream_with(&self, client: &ScryfallClient) -> crate::Result<ListStream<T>> {
+        Ok(self
+            .fetch_async_with(client)
+            .await?
+            .into_stream_with(client))
+    }
+
+    /// Asynchronously fetches the items from all pages of a list. If any of
+    /// the pages fail to load, returns an error.
+    pub async fn fetch_all_async(&self) -> crate::Result<Vec<T>> {
+        self.fetch_all_async_with(ScryfallClient::global()).await
+    }
+
+    /// Asynchronously fetches the items from all pages of a list using the
+    /// given client. If any of the pages fail to load, returns an error.
+    pub async fn fetch_all_async_with(&self, client: &ScryfallClient) -> crate::Result<Vec<T>> {
         let mut items = vec![];
-        let mut next_page = Some(self.fetch()?);
+        let mut next_page = Some(self.fetch_async_with(client).await?);
         while let Some(page) = next_page {
-            items.extend(page.data.into_iter());
+            items.extend(page.data);
             next_page = match page.next_page {
-                Some(uri) => Some(uri.fetch()?),
+                Some(uri) => Some(uri.fetch_async_with(client).await?),
                 None => None,
             };
         }
@@ -5,14 +5,16 @@ pub use uuid::Uuid;
 
 /// The [scryfall](https://scryfall.com/docs/api) endpoint.
 pub static ROOT_URL: Lazy<Url> = Lazy::new(|| Url::parse("https://api.scryfall.com/").unwrap());
-/// The [cards](https://scryfall.com/docs/api/cards) endpoint.
-pub static CARDS_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("cards/").unwrap());
-/// The [sets](https://scryfall.com/docs/api/sets) endpoint.
-pub static SETS_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("sets/").unwrap());
-/// The [bulk-data](https://scryfall.com/docs/api/bulk-data) endpoint.
-pub static BULK_DATA_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("bulk-data/").unwrap());
-/// The [catalog](https://scryfall.com/docs/api/catalogs) endpoint.
-pub static CATALOG_URL: Lazy<Url> = Lazy::new(|| ROOT_URL.join("catalog/").unwrap());
+/// The [cards](https://scryfall.com/docs/api/cards) path, relative to the root endpoint.
+pub const CARDS_PATH: &str = "cards/";
+/// The [sets](https://scryfall.com/docs/api/sets) path, relative to the root endpoint.
+pub const SETS_PATH: &str = "sets/";
+/// The [bulk-data](https://scryfall.com/docs/api/bulk-data) path, relative to the root endpoint.
+pub const BULK_DATA_PATH: &str = "bulk-data/";
+/// The [catalog](https://scryfall.com/docs/api/catalogs) path, relative to the root endpoint.
+pub const CATALOG_PATH: &str = "catalog/";
+/// The [symbology](https://scryfall.com/docs/api/card-symbols) path, relative to the root endpoint.
+pub const SYMBOLOGY_PATH: &str = "symbology/";
 
 /// The [rulings](https://scryfall.com/docs/api/rulings) path segment, which goes on the end of a
 /// card URL.